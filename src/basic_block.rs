@@ -1,17 +1,27 @@
-use std::ptr::null_mut;
+use std::marker::PhantomData;
 
-use llvm_sys::{core::LLVMGetBasicBlockTerminator, prelude::LLVMBasicBlockRef};
+use llvm_sys::{
+    core::{
+        LLVMBasicBlockAsValue, LLVMBlockAddress, LLVMGetBasicBlockParent,
+        LLVMGetBasicBlockTerminator, LLVMGetFirstInstruction, LLVMGetFirstUse,
+        LLVMGetInstructionParent, LLVMGetNextUse, LLVMGetUser, LLVMIsATerminatorInst,
+    },
+    prelude::LLVMBasicBlockRef,
+};
 
-use crate::value::Value;
+use crate::{
+    context::Context,
+    value::{FunctionValue, InstructionValue, PointerValue, Value},
+};
 
-#[derive(Debug, Clone)]
-pub struct BasicBlock(LLVMBasicBlockRef);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BasicBlock<'ctx>(LLVMBasicBlockRef, PhantomData<&'ctx Context>);
 
-impl BasicBlock {
+impl<'ctx> BasicBlock<'ctx> {
     /// Create a new [`BasicBlock`] from a [`LLVMBasicBlockRef`].
-    pub(crate) fn new(pointer: LLVMBasicBlockRef) -> BasicBlock {
-        assert_ne!(pointer, null_mut(), "basic block pointer is null");
-        BasicBlock(pointer)
+    pub(crate) fn new(pointer: LLVMBasicBlockRef) -> Self {
+        assert!(!pointer.is_null(), "basic block pointer is null");
+        Self(pointer, PhantomData)
     }
 
     #[inline]
@@ -20,14 +30,73 @@ impl BasicBlock {
         self.0
     }
 
-    /// Get the block terminator [`Value`].
-    pub fn get_block_terminator(&self) -> Option<Value> {
+    /// Get the block terminator [`InstructionValue`].
+    pub fn get_block_terminator(&self) -> Option<InstructionValue<'ctx>> {
         let value = unsafe { LLVMGetBasicBlockTerminator(self.get()) };
 
-        if value == null_mut() {
+        if value.is_null() {
             return None;
         }
 
-        Some(Value::new(value))
+        Some(InstructionValue::new(Value::new(value)))
+    }
+
+    /// Get the [`FunctionValue`] containing this [`BasicBlock`].
+    pub fn get_parent(&self) -> Option<FunctionValue<'ctx>> {
+        let value = unsafe { LLVMGetBasicBlockParent(self.get()) };
+
+        if value.is_null() {
+            return None;
+        }
+
+        Some(FunctionValue::new(Value::new(value)))
+    }
+
+    /// Get the `BlockAddress` constant of this [`BasicBlock`], used by `IndirectBranch`.
+    pub fn get_address(&self) -> Option<PointerValue<'ctx>> {
+        let func = self.get_parent()?;
+
+        unsafe {
+            Some(PointerValue::new(Value::new(LLVMBlockAddress(
+                func.get(),
+                self.get(),
+            ))))
+        }
+    }
+
+    /// Get the first [`InstructionValue`] of this [`BasicBlock`].
+    pub fn get_first_instruction(&self) -> Option<InstructionValue<'ctx>> {
+        let value = unsafe { LLVMGetFirstInstruction(self.get()) };
+
+        if value.is_null() {
+            return None;
+        }
+
+        Some(InstructionValue::new(Value::new(value)))
+    }
+
+    /// Get the predecessors of this [`BasicBlock`], once for every branch edge into it.
+    pub fn get_predecessors(&self) -> Vec<BasicBlock<'ctx>> {
+        let mut predecessors = Vec::new();
+        let mut current = unsafe { LLVMGetFirstUse(LLVMBasicBlockAsValue(self.get())) };
+
+        while !current.is_null() {
+            unsafe {
+                let user = LLVMGetUser(current);
+
+                // Branches built by an unpositioned builder are not inside any block.
+                if !LLVMIsATerminatorInst(user).is_null() {
+                    let parent = LLVMGetInstructionParent(user);
+
+                    if !parent.is_null() {
+                        predecessors.push(BasicBlock::new(parent));
+                    }
+                }
+
+                current = LLVMGetNextUse(current);
+            }
+        }
+
+        predecessors
     }
 }
@@ -1,16 +1,23 @@
-use std::ptr::null_mut;
+use std::{ffi::CString, marker::PhantomData};
 
 use llvm_sys::{
     core::{
-        LLVMBuildAShr, LLVMBuildAdd, LLVMBuildAlloca, LLVMBuildAnd, LLVMBuildBitCast, LLVMBuildBr,
-        LLVMBuildCall2, LLVMBuildCast, LLVMBuildCondBr, LLVMBuildFAdd, LLVMBuildFCmp,
-        LLVMBuildFDiv, LLVMBuildFMul, LLVMBuildFNeg, LLVMBuildFPCast, LLVMBuildFPExt,
-        LLVMBuildFPToSI, LLVMBuildFPToUI, LLVMBuildFPTrunc, LLVMBuildFRem, LLVMBuildFSub,
-        LLVMBuildICmp, LLVMBuildLoad2, LLVMBuildMul, LLVMBuildNeg, LLVMBuildNot, LLVMBuildOr,
-        LLVMBuildRet, LLVMBuildRetVoid, LLVMBuildSDiv, LLVMBuildSExt, LLVMBuildSIToFP,
-        LLVMBuildSRem, LLVMBuildStore, LLVMBuildSub, LLVMBuildUDiv, LLVMBuildUIToFP, LLVMBuildURem,
-        LLVMBuildZExt, LLVMConstInt, LLVMDisposeBuilder, LLVMGetInsertBlock, LLVMPositionBuilder,
-        LLVMPositionBuilderAtEnd, LLVMPositionBuilderBefore,
+        LLVMBuildAShr, LLVMBuildAdd, LLVMBuildAddrSpaceCast, LLVMBuildAlloca, LLVMBuildAnd,
+        LLVMBuildBinOp, LLVMBuildBitCast, LLVMBuildBr, LLVMBuildCall2, LLVMBuildCast,
+        LLVMBuildCondBr, LLVMBuildExactSDiv, LLVMBuildExactUDiv, LLVMBuildExtractElement,
+        LLVMBuildExtractValue, LLVMBuildFAdd, LLVMBuildFCmp, LLVMBuildFDiv, LLVMBuildFMul,
+        LLVMBuildFNeg, LLVMBuildFPCast, LLVMBuildFPExt, LLVMBuildFPToSI, LLVMBuildFPToUI,
+        LLVMBuildFPTrunc, LLVMBuildFRem, LLVMBuildFSub, LLVMBuildFreeze, LLVMBuildGEP2,
+        LLVMBuildICmp, LLVMBuildInBoundsGEP2, LLVMBuildIndirectBr, LLVMBuildInsertElement,
+        LLVMBuildInsertValue, LLVMBuildIntCast2, LLVMBuildIntToPtr, LLVMBuildLShr, LLVMBuildLoad2,
+        LLVMBuildMul, LLVMBuildNSWAdd, LLVMBuildNSWMul, LLVMBuildNSWNeg, LLVMBuildNSWSub,
+        LLVMBuildNUWAdd, LLVMBuildNUWMul, LLVMBuildNUWNeg, LLVMBuildNUWSub, LLVMBuildNeg,
+        LLVMBuildNot, LLVMBuildOr, LLVMBuildPhi, LLVMBuildPtrToInt, LLVMBuildRet, LLVMBuildRetVoid,
+        LLVMBuildSDiv, LLVMBuildSExt, LLVMBuildSIToFP, LLVMBuildSRem, LLVMBuildSelect,
+        LLVMBuildShl, LLVMBuildShuffleVector, LLVMBuildStore, LLVMBuildStructGEP2, LLVMBuildSub,
+        LLVMBuildSwitch, LLVMBuildTrunc, LLVMBuildUDiv, LLVMBuildUIToFP, LLVMBuildURem,
+        LLVMBuildUnreachable, LLVMBuildXor, LLVMBuildZExt, LLVMDisposeBuilder, LLVMGetInsertBlock,
+        LLVMPositionBuilder, LLVMPositionBuilderAtEnd, LLVMPositionBuilderBefore,
     },
     prelude::{LLVMBuilderRef, LLVMValueRef},
     LLVMIntPredicate, LLVMOpcode, LLVMRealPredicate,
@@ -18,19 +25,27 @@ use llvm_sys::{
 
 use crate::{
     basic_block::BasicBlock,
-    ty::Type,
-    util::{string_to_cstring, EMPTY_TWINE},
-    value::Value,
+    context::Context,
+    error::Error,
+    ty::{FloatType, FunctionType, IntType, PointerType, StructType, Type, TypeKind},
+    util::string_to_cstring,
+    value::{
+        FloatValue, FunctionValue, IndirectBrValue, InstructionValue, IntValue, PhiValue,
+        PointerValue, SwitchValue, Value,
+    },
 };
 
-#[derive(Debug, Clone)]
-pub struct Builder(LLVMBuilderRef);
+#[derive(Debug)]
+pub struct Builder<'ctx>(LLVMBuilderRef, PhantomData<&'ctx Context>);
 
-impl Builder {
+impl<'ctx> Builder<'ctx> {
     /// Create a new [`Builder`] from a [`LLVMBuilderRef`].
-    pub(crate) fn new(pointer: LLVMBuilderRef) -> Self {
-        assert_ne!(pointer, null_mut(), "builder pointer is null");
-        Self(pointer)
+    pub(crate) fn new(pointer: LLVMBuilderRef) -> Result<Self, Error> {
+        if pointer.is_null() {
+            return Err(Error::NullHandle("builder"));
+        }
+
+        Ok(Self(pointer, PhantomData))
     }
 
     #[inline]
@@ -39,445 +54,1337 @@ impl Builder {
         self.0
     }
 
-    /// Positions the builder before the given Instruction/[`Value`].
-    pub fn position(&self, block: &BasicBlock, instr: &Value) {
+    /// Positions the builder before the given [`InstructionValue`].
+    pub fn position(&self, block: &BasicBlock<'ctx>, instr: &InstructionValue<'ctx>) {
         unsafe { LLVMPositionBuilder(self.get(), block.get(), instr.get()) }
     }
 
-    /// Position the builder before the given Instruction/[`Value`] in a given [`BasicBlock`].
-    pub fn position_before(&self, instr: &Value) {
+    /// Position the builder before the given [`InstructionValue`] in its [`BasicBlock`].
+    pub fn position_before(&self, instr: &InstructionValue<'ctx>) {
         unsafe { LLVMPositionBuilderBefore(self.get(), instr.get()) }
     }
 
     /// Position the builder at the end of a [`BasicBlockRef`].
-    pub fn position_at_end(&self, block: &BasicBlock) {
+    pub fn position_at_end(&self, block: &BasicBlock<'ctx>) {
         unsafe { LLVMPositionBuilderAtEnd(self.get(), block.get()) }
     }
 
-    /// Get insertion block.
-    pub fn get_insert_block(&self) -> BasicBlock {
-        unsafe { BasicBlock::new(LLVMGetInsertBlock(self.get())) }
+    /// Get insertion block, `None` if the builder has not been positioned.
+    pub fn get_insert_block(&self) -> Option<BasicBlock<'ctx>> {
+        let block = unsafe { LLVMGetInsertBlock(self.get()) };
+
+        if block.is_null() {
+            return None;
+        }
+
+        Some(BasicBlock::new(block))
     }
 
     /// Build an `Add` instruction.
-    pub fn build_add(&self, left: &Value, right: &Value) -> Value {
+    pub fn build_add<S: ToString>(
+        &self,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildAdd(
+            IntValue::new(Value::new(LLVMBuildAdd(
                 self.get(),
                 left.get(),
                 right.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `Sub` instruction.
-    pub fn build_sub(&self, left: &Value, right: &Value) -> Value {
+    /// Build an `Add nsw` instruction.
+    pub fn build_nsw_add<S: ToString>(
+        &self,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildSub(
+            IntValue::new(Value::new(LLVMBuildNSWAdd(
                 self.get(),
                 left.get(),
                 right.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `Mul` instruction.
-    pub fn build_mul(&self, left: &Value, right: &Value) -> Value {
+    /// Build an `Add nuw` instruction.
+    pub fn build_nuw_add<S: ToString>(
+        &self,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildMul(
+            IntValue::new(Value::new(LLVMBuildNUWAdd(
                 self.get(),
                 left.get(),
                 right.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `SDiv` instruction.
-    pub fn build_sdiv(&self, left: &Value, right: &Value) -> Value {
+    /// Build a `Sub` instruction.
+    pub fn build_sub<S: ToString>(
+        &self,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildSDiv(
+            IntValue::new(Value::new(LLVMBuildSub(
                 self.get(),
                 left.get(),
                 right.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `UDiv` instruction.
-    pub fn build_udiv(&self, left: &Value, right: &Value) -> Value {
+    /// Build a `Sub nsw` instruction.
+    pub fn build_nsw_sub<S: ToString>(
+        &self,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildUDiv(
+            IntValue::new(Value::new(LLVMBuildNSWSub(
                 self.get(),
                 left.get(),
                 right.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `SRem` instruction.
-    pub fn build_srem(&self, left: &Value, right: &Value) -> Value {
+    /// Build a `Sub nuw` instruction.
+    pub fn build_nuw_sub<S: ToString>(
+        &self,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildSRem(
+            IntValue::new(Value::new(LLVMBuildNUWSub(
                 self.get(),
                 left.get(),
                 right.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `URem` instruction.
-    pub fn build_urem(&self, left: &Value, right: &Value) -> Value {
+    /// Build a `Mul` instruction.
+    pub fn build_mul<S: ToString>(
+        &self,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildURem(
+            IntValue::new(Value::new(LLVMBuildMul(
                 self.get(),
                 left.get(),
                 right.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build an `ICmp` instruction.
-    pub fn build_icmp(&self, op: LLVMIntPredicate, left: &Value, right: &Value) -> Value {
+    /// Build a `Mul nsw` instruction.
+    pub fn build_nsw_mul<S: ToString>(
+        &self,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildICmp(
+            IntValue::new(Value::new(LLVMBuildNSWMul(
                 self.get(),
-                op,
                 left.get(),
                 right.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build an `ArithmeticShiftRight` instruction.
-    pub fn build_ashr(&self, left: &Value, right: &Value) -> Value {
+    /// Build a `Mul nuw` instruction.
+    pub fn build_nuw_mul<S: ToString>(
+        &self,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildAShr(
+            IntValue::new(Value::new(LLVMBuildNUWMul(
                 self.get(),
                 left.get(),
                 right.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build an `And` instruction.
-    pub fn build_and(&self, left: &Value, right: &Value) -> Value {
+    /// Build a `SDiv` instruction.
+    pub fn build_sdiv<S: ToString>(
+        &self,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildAnd(
+            IntValue::new(Value::new(LLVMBuildSDiv(
                 self.get(),
                 left.get(),
                 right.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `BitCast` instruction.
-    pub fn build_bit_cast(&self, ty: &Type, value: &Value) -> Value {
+    /// Build a `SDiv exact` instruction.
+    pub fn build_exact_sdiv<S: ToString>(
+        &self,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildBitCast(
+            IntValue::new(Value::new(LLVMBuildExactSDiv(
                 self.get(),
-                value.get(),
-                ty.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                left.get(),
+                right.get(),
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `FAdd` instruction.
-    pub fn build_fadd(&self, left: &Value, right: &Value) -> Value {
+    /// Build a `UDiv` instruction.
+    pub fn build_udiv<S: ToString>(
+        &self,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildFAdd(
+            IntValue::new(Value::new(LLVMBuildUDiv(
                 self.get(),
                 left.get(),
                 right.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `FCmp` instruction.
-    pub fn build_fcmp(&self, predicate: LLVMRealPredicate, left: &Value, right: &Value) -> Value {
+    /// Build a `UDiv exact` instruction.
+    pub fn build_exact_udiv<S: ToString>(
+        &self,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildFCmp(
+            IntValue::new(Value::new(LLVMBuildExactUDiv(
                 self.get(),
-                predicate,
                 left.get(),
                 right.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `FDiv` instruction.
-    pub fn build_fdiv(&self, left: &Value, right: &Value) -> Value {
+    /// Build a `SRem` instruction.
+    pub fn build_srem<S: ToString>(
+        &self,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildFDiv(
+            IntValue::new(Value::new(LLVMBuildSRem(
                 self.get(),
                 left.get(),
                 right.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `FMul` instruction.
-    pub fn build_fmul(&self, left: &Value, right: &Value) -> Value {
+    /// Build a `URem` instruction.
+    pub fn build_urem<S: ToString>(
+        &self,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildFMul(
+            IntValue::new(Value::new(LLVMBuildURem(
                 self.get(),
                 left.get(),
                 right.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `FNeg` instruction.
-    pub fn build_fneg(&self, value: &Value) -> Value {
-        unsafe { Value::new(LLVMBuildFNeg(self.get(), value.get(), EMPTY_TWINE.as_ptr())) }
-    }
+    /// Build an `ICmp` instruction.
+    pub fn build_icmp<S: ToString>(
+        &self,
+        op: IntPredicate,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
 
-    /// Build a `FPCast` instruction.
-    pub fn build_fpcast(&self, value: &Value, ty: &Type) -> Value {
         unsafe {
-            Value::new(LLVMBuildFPCast(
+            IntValue::new(Value::new(LLVMBuildICmp(
                 self.get(),
-                value.get(),
-                ty.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                op.into(),
+                left.get(),
+                right.get(),
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `FPExt` instruction.
-    pub fn build_fpext(&self, value: &Value, ty: &Type) -> Value {
+    /// Build an `ICmp` instruction comparing two pointers.
+    pub fn build_pointer_icmp<S: ToString>(
+        &self,
+        op: IntPredicate,
+        left: &PointerValue<'ctx>,
+        right: &PointerValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildFPExt(
+            IntValue::new(Value::new(LLVMBuildICmp(
                 self.get(),
-                value.get(),
-                ty.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                op.into(),
+                left.get(),
+                right.get(),
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `FPToSI` instruction.
-    pub fn build_fptosi(&self, value: &Value, ty: &Type) -> Value {
+    /// Build an `ArithmeticShiftRight` instruction.
+    pub fn build_ashr<S: ToString>(
+        &self,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildFPToSI(
+            IntValue::new(Value::new(LLVMBuildAShr(
                 self.get(),
-                value.get(),
-                ty.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                left.get(),
+                right.get(),
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `FPToUI` instruction.
-    pub fn build_fptoui(&self, value: &Value, ty: &Type) -> Value {
+    /// Build a `LogicalShiftRight` instruction.
+    pub fn build_lshr<S: ToString>(
+        &self,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildFPToUI(
+            IntValue::new(Value::new(LLVMBuildLShr(
                 self.get(),
-                value.get(),
-                ty.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                left.get(),
+                right.get(),
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `FPTrunc` instruction.
-    pub fn build_fptrunc(&self, value: &Value, ty: &Type) -> Value {
+    /// Build a `ShiftLeft` instruction.
+    pub fn build_shl<S: ToString>(
+        &self,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildFPTrunc(
+            IntValue::new(Value::new(LLVMBuildShl(
                 self.get(),
-                value.get(),
-                ty.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                left.get(),
+                right.get(),
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `FRem` instruction.
-    pub fn build_frem(&self, left: &Value, right: &Value) -> Value {
+    /// Build an `And` instruction.
+    pub fn build_and<S: ToString>(
+        &self,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildFRem(
+            IntValue::new(Value::new(LLVMBuildAnd(
                 self.get(),
                 left.get(),
                 right.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `FSub` instruction.
-    pub fn build_fsub(&self, left: &Value, right: &Value) -> Value {
+    /// Build a `Xor` instruction.
+    pub fn build_xor<S: ToString>(
+        &self,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildFSub(
+            IntValue::new(Value::new(LLVMBuildXor(
                 self.get(),
                 left.get(),
                 right.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build an `Or` instruction.
-    pub fn build_or(&self, left: &Value, right: &Value) -> Value {
+    /// Build a binary instruction from its [`Opcode`], or `None` if it is not a binary
+    /// operator.
+    pub fn build_binop<S: ToString>(
+        &self,
+        op: Opcode,
+        left: &Value<'ctx>,
+        right: &Value<'ctx>,
+        twine: S,
+    ) -> Option<Value<'ctx>> {
+        if !op.is_binary_op() {
+            return None;
+        }
+
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildOr(
+            Some(Value::new(LLVMBuildBinOp(
                 self.get(),
+                op.into(),
                 left.get(),
                 right.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `SItoFP` instruction.
-    pub fn build_sitofp(&self, value: &Value, ty: &Type) -> Value {
+    /// Build a `BitCast` instruction.
+    pub fn build_bit_cast<S: ToString>(
+        &self,
+        value: &Value<'ctx>,
+        ty: &Type<'ctx>,
+        twine: S,
+    ) -> Value<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildSIToFP(
+            Value::new(LLVMBuildBitCast(
                 self.get(),
                 value.get(),
                 ty.get(),
-                EMPTY_TWINE.as_ptr(),
+                twine.as_ptr(),
             ))
         }
     }
 
-    /// Build a `UItoFP` instruction.
-    pub fn build_uitofp(&self, value: &Value, ty: &Type) -> Value {
+    /// Build a `FAdd` instruction.
+    pub fn build_fadd<S: ToString>(
+        &self,
+        left: &FloatValue<'ctx>,
+        right: &FloatValue<'ctx>,
+        twine: S,
+    ) -> FloatValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildUIToFP(
+            FloatValue::new(Value::new(LLVMBuildFAdd(
                 self.get(),
-                value.get(),
-                ty.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                left.get(),
+                right.get(),
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `Cast` instruction.
-    pub fn build_cast(&self, op: LLVMOpcode, value: &Value, ty: &Type) -> Value {
+    /// Build a `FCmp` instruction.
+    pub fn build_fcmp<S: ToString>(
+        &self,
+        predicate: RealPredicate,
+        left: &FloatValue<'ctx>,
+        right: &FloatValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildCast(
+            IntValue::new(Value::new(LLVMBuildFCmp(
                 self.get(),
-                op,
-                value.get(),
-                ty.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                predicate.into(),
+                left.get(),
+                right.get(),
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `Neg` instruction.
-    pub fn build_neg(&self, value: &Value) -> Value {
-        unsafe { Value::new(LLVMBuildNeg(self.get(), value.get(), EMPTY_TWINE.as_ptr())) }
-    }
-
-    /// Build a `Not` isntruction.
-    pub fn build_not(&self, value: &Value) -> Value {
-        unsafe { Value::new(LLVMBuildNot(self.get(), value.get(), EMPTY_TWINE.as_ptr())) }
-    }
-
-    /// Build an `Alloca` instruction.
-    pub fn build_alloca<S: ToString>(&self, ty: &Type, twine: S) -> Value {
+    /// Build a `FDiv` instruction.
+    pub fn build_fdiv<S: ToString>(
+        &self,
+        left: &FloatValue<'ctx>,
+        right: &FloatValue<'ctx>,
+        twine: S,
+    ) -> FloatValue<'ctx> {
         let twine = string_to_cstring(twine.to_string());
 
-        unsafe { Value::new(LLVMBuildAlloca(self.get(), ty.get(), twine.as_ptr())) }
-    }
-
-    /// Build a `Load` instruction.
-    pub fn build_load(&self, ty: &Type, pointer: &Value) -> Value {
         unsafe {
-            Value::new(LLVMBuildLoad2(
+            FloatValue::new(Value::new(LLVMBuildFDiv(
                 self.get(),
-                ty.get(),
-                pointer.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                left.get(),
+                right.get(),
+                twine.as_ptr(),
+            )))
         }
     }
 
-    /// Build a `Store` instruction.
-    pub fn build_store(&self, value: &Value, pointer: &Value) -> Value {
-        unsafe { Value::new(LLVMBuildStore(self.get(), value.get(), pointer.get())) }
-    }
+    /// Build a `FMul` instruction.
+    pub fn build_fmul<S: ToString>(
+        &self,
+        left: &FloatValue<'ctx>,
+        right: &FloatValue<'ctx>,
+        twine: S,
+    ) -> FloatValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            FloatValue::new(Value::new(LLVMBuildFMul(
+                self.get(),
+                left.get(),
+                right.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `FNeg` instruction.
+    pub fn build_fneg<S: ToString>(&self, value: &FloatValue<'ctx>, twine: S) -> FloatValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            FloatValue::new(Value::new(LLVMBuildFNeg(
+                self.get(),
+                value.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `FPCast` instruction.
+    pub fn build_fpcast<S: ToString>(
+        &self,
+        value: &FloatValue<'ctx>,
+        ty: &FloatType<'ctx>,
+        twine: S,
+    ) -> FloatValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            FloatValue::new(Value::new(LLVMBuildFPCast(
+                self.get(),
+                value.get(),
+                ty.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `FPExt` instruction.
+    pub fn build_fpext<S: ToString>(
+        &self,
+        value: &FloatValue<'ctx>,
+        ty: &FloatType<'ctx>,
+        twine: S,
+    ) -> FloatValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            FloatValue::new(Value::new(LLVMBuildFPExt(
+                self.get(),
+                value.get(),
+                ty.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `FPToSI` instruction.
+    pub fn build_fptosi<S: ToString>(
+        &self,
+        value: &FloatValue<'ctx>,
+        ty: &IntType<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            IntValue::new(Value::new(LLVMBuildFPToSI(
+                self.get(),
+                value.get(),
+                ty.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `FPToUI` instruction.
+    pub fn build_fptoui<S: ToString>(
+        &self,
+        value: &FloatValue<'ctx>,
+        ty: &IntType<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            IntValue::new(Value::new(LLVMBuildFPToUI(
+                self.get(),
+                value.get(),
+                ty.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `FPTrunc` instruction.
+    pub fn build_fptrunc<S: ToString>(
+        &self,
+        value: &FloatValue<'ctx>,
+        ty: &FloatType<'ctx>,
+        twine: S,
+    ) -> FloatValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            FloatValue::new(Value::new(LLVMBuildFPTrunc(
+                self.get(),
+                value.get(),
+                ty.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `FRem` instruction.
+    pub fn build_frem<S: ToString>(
+        &self,
+        left: &FloatValue<'ctx>,
+        right: &FloatValue<'ctx>,
+        twine: S,
+    ) -> FloatValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            FloatValue::new(Value::new(LLVMBuildFRem(
+                self.get(),
+                left.get(),
+                right.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `FSub` instruction.
+    pub fn build_fsub<S: ToString>(
+        &self,
+        left: &FloatValue<'ctx>,
+        right: &FloatValue<'ctx>,
+        twine: S,
+    ) -> FloatValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            FloatValue::new(Value::new(LLVMBuildFSub(
+                self.get(),
+                left.get(),
+                right.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build an `Or` instruction.
+    pub fn build_or<S: ToString>(
+        &self,
+        left: &IntValue<'ctx>,
+        right: &IntValue<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            IntValue::new(Value::new(LLVMBuildOr(
+                self.get(),
+                left.get(),
+                right.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `SItoFP` instruction.
+    pub fn build_sitofp<S: ToString>(
+        &self,
+        value: &IntValue<'ctx>,
+        ty: &FloatType<'ctx>,
+        twine: S,
+    ) -> FloatValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            FloatValue::new(Value::new(LLVMBuildSIToFP(
+                self.get(),
+                value.get(),
+                ty.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `UItoFP` instruction.
+    pub fn build_uitofp<S: ToString>(
+        &self,
+        value: &IntValue<'ctx>,
+        ty: &FloatType<'ctx>,
+        twine: S,
+    ) -> FloatValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            FloatValue::new(Value::new(LLVMBuildUIToFP(
+                self.get(),
+                value.get(),
+                ty.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `Cast` instruction from its [`Opcode`], or `None` if it is not a cast.
+    pub fn build_cast<S: ToString>(
+        &self,
+        op: Opcode,
+        value: &Value<'ctx>,
+        ty: &Type<'ctx>,
+        twine: S,
+    ) -> Option<Value<'ctx>> {
+        if !op.is_cast() {
+            return None;
+        }
+
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            Some(Value::new(LLVMBuildCast(
+                self.get(),
+                op.into(),
+                value.get(),
+                ty.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `Neg` instruction.
+    pub fn build_neg<S: ToString>(&self, value: &IntValue<'ctx>, twine: S) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            IntValue::new(Value::new(LLVMBuildNeg(
+                self.get(),
+                value.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `Neg nsw` instruction.
+    pub fn build_nsw_neg<S: ToString>(&self, value: &IntValue<'ctx>, twine: S) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            IntValue::new(Value::new(LLVMBuildNSWNeg(
+                self.get(),
+                value.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `Neg nuw` instruction.
+    pub fn build_nuw_neg<S: ToString>(&self, value: &IntValue<'ctx>, twine: S) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            IntValue::new(Value::new(LLVMBuildNUWNeg(
+                self.get(),
+                value.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `Not` isntruction.
+    pub fn build_not<S: ToString>(&self, value: &IntValue<'ctx>, twine: S) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            IntValue::new(Value::new(LLVMBuildNot(
+                self.get(),
+                value.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build an `Alloca` instruction.
+    pub fn build_alloca<S: ToString>(&self, ty: &Type<'ctx>, twine: S) -> PointerValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            PointerValue::new(Value::new(LLVMBuildAlloca(
+                self.get(),
+                ty.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `Phi` instruction, incoming values are added on the returned [`PhiValue`].
+    pub fn build_phi<S: ToString>(&self, ty: &Type<'ctx>, twine: S) -> PhiValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            PhiValue::new(Value::new(LLVMBuildPhi(
+                self.get(),
+                ty.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `Load` instruction.
+    pub fn build_load<S: ToString>(
+        &self,
+        ty: &Type<'ctx>,
+        pointer: &PointerValue<'ctx>,
+        twine: S,
+    ) -> Value<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            Value::new(LLVMBuildLoad2(
+                self.get(),
+                ty.get(),
+                pointer.get(),
+                twine.as_ptr(),
+            ))
+        }
+    }
+
+    /// Build a `GetElementPtr` instruction, indexing into `pointer` as a `ty`.
+    pub fn build_gep<S: ToString>(
+        &self,
+        ty: &Type<'ctx>,
+        pointer: &PointerValue<'ctx>,
+        indices: &[IntValue<'ctx>],
+        twine: S,
+    ) -> PointerValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        let mut indices = indices
+            .iter()
+            .map(|index| index.get())
+            .collect::<Vec<LLVMValueRef>>();
+
+        unsafe {
+            PointerValue::new(Value::new(LLVMBuildGEP2(
+                self.get(),
+                ty.get(),
+                pointer.get(),
+                indices.as_mut_ptr(),
+                indices.len() as u32,
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `GetElementPtr inbounds` instruction, indexing into `pointer` as a `ty`.
+    pub fn build_inbounds_gep<S: ToString>(
+        &self,
+        ty: &Type<'ctx>,
+        pointer: &PointerValue<'ctx>,
+        indices: &[IntValue<'ctx>],
+        twine: S,
+    ) -> PointerValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        let mut indices = indices
+            .iter()
+            .map(|index| index.get())
+            .collect::<Vec<LLVMValueRef>>();
+
+        unsafe {
+            PointerValue::new(Value::new(LLVMBuildInBoundsGEP2(
+                self.get(),
+                ty.get(),
+                pointer.get(),
+                indices.as_mut_ptr(),
+                indices.len() as u32,
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `GetElementPtr` instruction to a field of `struct_ty`.
+    pub fn build_struct_gep<S: ToString>(
+        &self,
+        struct_ty: &StructType<'ctx>,
+        pointer: &PointerValue<'ctx>,
+        index: u32,
+        twine: S,
+    ) -> PointerValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            PointerValue::new(Value::new(LLVMBuildStructGEP2(
+                self.get(),
+                struct_ty.get(),
+                pointer.get(),
+                index,
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build an `ExtractValue` instruction.
+    pub fn build_extract_value<S: ToString>(
+        &self,
+        aggregate: &Value<'ctx>,
+        index: u32,
+        twine: S,
+    ) -> Value<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            Value::new(LLVMBuildExtractValue(
+                self.get(),
+                aggregate.get(),
+                index,
+                twine.as_ptr(),
+            ))
+        }
+    }
+
+    /// Build an `InsertValue` instruction.
+    pub fn build_insert_value<S: ToString>(
+        &self,
+        aggregate: &Value<'ctx>,
+        element: &Value<'ctx>,
+        index: u32,
+        twine: S,
+    ) -> Value<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            Value::new(LLVMBuildInsertValue(
+                self.get(),
+                aggregate.get(),
+                element.get(),
+                index,
+                twine.as_ptr(),
+            ))
+        }
+    }
+
+    /// Build an `ExtractElement` instruction.
+    pub fn build_extract_element<S: ToString>(
+        &self,
+        vector: &Value<'ctx>,
+        index: &IntValue<'ctx>,
+        twine: S,
+    ) -> Value<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            Value::new(LLVMBuildExtractElement(
+                self.get(),
+                vector.get(),
+                index.get(),
+                twine.as_ptr(),
+            ))
+        }
+    }
+
+    /// Build an `InsertElement` instruction.
+    pub fn build_insert_element<S: ToString>(
+        &self,
+        vector: &Value<'ctx>,
+        element: &Value<'ctx>,
+        index: &IntValue<'ctx>,
+        twine: S,
+    ) -> Value<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            Value::new(LLVMBuildInsertElement(
+                self.get(),
+                vector.get(),
+                element.get(),
+                index.get(),
+                twine.as_ptr(),
+            ))
+        }
+    }
+
+    /// Build a `ShuffleVector` instruction.
+    pub fn build_shuffle_vector<S: ToString>(
+        &self,
+        left: &Value<'ctx>,
+        right: &Value<'ctx>,
+        mask: &Value<'ctx>,
+        twine: S,
+    ) -> Value<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            Value::new(LLVMBuildShuffleVector(
+                self.get(),
+                left.get(),
+                right.get(),
+                mask.get(),
+                twine.as_ptr(),
+            ))
+        }
+    }
+
+    /// Build a `Store` instruction.
+    pub fn build_store(
+        &self,
+        value: &Value<'ctx>,
+        pointer: &PointerValue<'ctx>,
+    ) -> InstructionValue<'ctx> {
+        unsafe {
+            InstructionValue::new(Value::new(LLVMBuildStore(
+                self.get(),
+                value.get(),
+                pointer.get(),
+            )))
+        }
+    }
 
     /// Build a `ReturnVoid` instruction.
-    pub fn build_return_void(&self) -> Value {
-        unsafe { Value::new(LLVMBuildRetVoid(self.get())) }
+    pub fn build_return_void(&self) -> InstructionValue<'ctx> {
+        unsafe { InstructionValue::new(Value::new(LLVMBuildRetVoid(self.get()))) }
     }
 
     /// Build a `Return` instruction.
-    pub fn build_return(&self, value: &Value) -> Value {
-        unsafe { Value::new(LLVMBuildRet(self.get(), value.get())) }
+    pub fn build_return(&self, value: &Value<'ctx>) -> InstructionValue<'ctx> {
+        unsafe { InstructionValue::new(Value::new(LLVMBuildRet(self.get(), value.get()))) }
     }
 
     /// Build a `SignExtension` instruction.
-    pub fn build_sext(&self, dest_ty: &Type, value: &Value) -> Value {
+    pub fn build_sext<S: ToString>(
+        &self,
+        value: &IntValue<'ctx>,
+        dest_ty: &IntType<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildSExt(
+            IntValue::new(Value::new(LLVMBuildSExt(
                 self.get(),
                 value.get(),
                 dest_ty.get(),
-                EMPTY_TWINE.as_ptr(),
-            ))
+                twine.as_ptr(),
+            )))
         }
     }
 
     /// Build a `ZeroExtension` instruction.
-    pub fn build_zext(&self, dest_ty: &Type, value: &Value) -> Value {
+    pub fn build_zext<S: ToString>(
+        &self,
+        value: &IntValue<'ctx>,
+        dest_ty: &IntType<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            IntValue::new(Value::new(LLVMBuildZExt(
+                self.get(),
+                value.get(),
+                dest_ty.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `Trunc` instruction.
+    pub fn build_trunc<S: ToString>(
+        &self,
+        value: &IntValue<'ctx>,
+        dest_ty: &IntType<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            IntValue::new(Value::new(LLVMBuildTrunc(
+                self.get(),
+                value.get(),
+                dest_ty.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `PtrToInt` instruction.
+    pub fn build_ptrtoint<S: ToString>(
+        &self,
+        value: &PointerValue<'ctx>,
+        dest_ty: &IntType<'ctx>,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            IntValue::new(Value::new(LLVMBuildPtrToInt(
+                self.get(),
+                value.get(),
+                dest_ty.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `IntToPtr` instruction.
+    pub fn build_inttoptr<S: ToString>(
+        &self,
+        value: &IntValue<'ctx>,
+        dest_ty: &PointerType<'ctx>,
+        twine: S,
+    ) -> PointerValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            PointerValue::new(Value::new(LLVMBuildIntToPtr(
+                self.get(),
+                value.get(),
+                dest_ty.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `AddrSpaceCast` instruction.
+    pub fn build_addrspacecast<S: ToString>(
+        &self,
+        value: &PointerValue<'ctx>,
+        dest_ty: &PointerType<'ctx>,
+        twine: S,
+    ) -> PointerValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            PointerValue::new(Value::new(LLVMBuildAddrSpaceCast(
+                self.get(),
+                value.get(),
+                dest_ty.get(),
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `Trunc`, `SExt` or `ZExt` instruction, whichever converts `value` to `dest_ty`.
+    ///
+    /// `is_signed` selects sign extension over zero extension when `dest_ty` is wider.
+    pub fn build_int_cast<S: ToString>(
+        &self,
+        value: &IntValue<'ctx>,
+        dest_ty: &IntType<'ctx>,
+        is_signed: bool,
+        twine: S,
+    ) -> IntValue<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
         unsafe {
-            Value::new(LLVMBuildZExt(
+            IntValue::new(Value::new(LLVMBuildIntCast2(
                 self.get(),
                 value.get(),
                 dest_ty.get(),
-                EMPTY_TWINE.as_ptr(),
+                is_signed as i32,
+                twine.as_ptr(),
+            )))
+        }
+    }
+
+    /// Build a `Select` instruction.
+    pub fn build_select<S: ToString>(
+        &self,
+        condition: &IntValue<'ctx>,
+        then_value: &Value<'ctx>,
+        else_value: &Value<'ctx>,
+        twine: S,
+    ) -> Value<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe {
+            Value::new(LLVMBuildSelect(
+                self.get(),
+                condition.get(),
+                then_value.get(),
+                else_value.get(),
+                twine.as_ptr(),
             ))
         }
     }
 
+    /// Build a `Freeze` instruction.
+    pub fn build_freeze<S: ToString>(&self, value: &Value<'ctx>, twine: S) -> Value<'ctx> {
+        let twine = string_to_cstring(twine.to_string());
+
+        unsafe { Value::new(LLVMBuildFreeze(self.get(), value.get(), twine.as_ptr())) }
+    }
+
     /// Build a `CondBranch` instruction.
     pub fn build_cond_br(
         &self,
-        condition: &Value,
-        then_block: &BasicBlock,
-        else_block: &BasicBlock,
-    ) {
+        condition: &IntValue<'ctx>,
+        then_block: &BasicBlock<'ctx>,
+        else_block: &BasicBlock<'ctx>,
+    ) -> InstructionValue<'ctx> {
         unsafe {
-            LLVMBuildCondBr(
+            InstructionValue::new(Value::new(LLVMBuildCondBr(
                 self.get(),
                 condition.get(),
                 then_block.get(),
                 else_block.get(),
-            )
-        };
+            )))
+        }
     }
 
     /// Build a `Branch` instruction.
-    pub fn build_br(&self, block: &BasicBlock) {
-        unsafe { LLVMBuildBr(self.get(), block.get()) };
+    pub fn build_br(&self, block: &BasicBlock<'ctx>) -> InstructionValue<'ctx> {
+        unsafe { InstructionValue::new(Value::new(LLVMBuildBr(self.get(), block.get()))) }
+    }
+
+    /// Build a `Switch` instruction, cases are added with [`SwitchValue::add_case`].
+    pub fn build_switch(
+        &self,
+        value: &IntValue<'ctx>,
+        default_block: &BasicBlock<'ctx>,
+        case_count: u32,
+    ) -> SwitchValue<'ctx> {
+        unsafe {
+            SwitchValue::new(Value::new(LLVMBuildSwitch(
+                self.get(),
+                value.get(),
+                default_block.get(),
+                case_count,
+            )))
+        }
+    }
+
+    /// Build an `IndirectBranch` instruction to a [`BasicBlock::get_address`], destinations are
+    /// added with [`IndirectBrValue::add_destination`].
+    pub fn build_indirect_br(
+        &self,
+        address: &PointerValue<'ctx>,
+        destination_count: u32,
+    ) -> IndirectBrValue<'ctx> {
+        unsafe {
+            IndirectBrValue::new(Value::new(LLVMBuildIndirectBr(
+                self.get(),
+                address.get(),
+                destination_count,
+            )))
+        }
+    }
+
+    /// Build an `Unreachable` instruction.
+    pub fn build_unreachable(&self) -> InstructionValue<'ctx> {
+        unsafe { InstructionValue::new(Value::new(LLVMBuildUnreachable(self.get()))) }
     }
 
     /// Build a `Call` instruction.
     pub fn build_call<S: ToString>(
         &self,
-        func_ty: &Type,
-        func: &Value,
-        arguments: &[Value],
+        func_ty: &FunctionType<'ctx>,
+        func: &FunctionValue<'ctx>,
+        arguments: &[Value<'ctx>],
         twine: S,
-    ) -> Value {
-        let twine = string_to_cstring(twine.to_string());
+    ) -> Value<'ctx> {
+        let twine = call_twine(func_ty, twine);
 
         let mut arguments = arguments
             .iter()
@@ -496,13 +1403,414 @@ impl Builder {
         }
     }
 
-    /// Create a const integer.
-    pub fn const_int(&self, integer_ty: &Type, value: u64, sign_extend: bool) -> Value {
-        unsafe { Value::new(LLVMConstInt(integer_ty.get(), value, sign_extend as i32)) }
+    /// Build a `Call` instruction through a function pointer of type `func_ty`.
+    pub fn build_indirect_call<S: ToString>(
+        &self,
+        func_ty: &FunctionType<'ctx>,
+        func_pointer: &PointerValue<'ctx>,
+        arguments: &[Value<'ctx>],
+        twine: S,
+    ) -> Value<'ctx> {
+        let twine = call_twine(func_ty, twine);
+
+        let mut arguments = arguments
+            .iter()
+            .map(|arg| arg.get())
+            .collect::<Vec<LLVMValueRef>>();
+
+        unsafe {
+            Value::new(LLVMBuildCall2(
+                self.get(),
+                func_ty.get(),
+                func_pointer.get(),
+                arguments.as_mut_ptr(),
+                arguments.len() as u32,
+                twine.as_ptr(),
+            ))
+        }
+    }
+}
+
+/// Get the name of a call through `func_ty`, LLVM cannot name the result of a `void` call.
+fn call_twine<S: ToString>(func_ty: &FunctionType<'_>, twine: S) -> CString {
+    if func_ty.get_return_type().get_type_kind() == TypeKind::Void {
+        return CString::default();
     }
 
-    /// Dispose the builder and free its memory.
-    pub fn dispose(self) {
+    string_to_cstring(twine.to_string())
+}
+
+impl Drop for Builder<'_> {
+    fn drop(&mut self) {
         unsafe { LLVMDisposeBuilder(self.get()) }
     }
 }
+
+/// Predicates of the `ICmp` instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntPredicate {
+    /// Equal.
+    Eq,
+    /// Not equal.
+    Ne,
+    /// Unsigned greater than.
+    Ugt,
+    /// Unsigned greater or equal.
+    Uge,
+    /// Unsigned less than.
+    Ult,
+    /// Unsigned less or equal.
+    Ule,
+    /// Signed greater than.
+    Sgt,
+    /// Signed greater or equal.
+    Sge,
+    /// Signed less than.
+    Slt,
+    /// Signed less or equal.
+    Sle,
+}
+
+impl From<IntPredicate> for LLVMIntPredicate {
+    fn from(value: IntPredicate) -> Self {
+        match value {
+            IntPredicate::Eq => Self::LLVMIntEQ,
+            IntPredicate::Ne => Self::LLVMIntNE,
+            IntPredicate::Ugt => Self::LLVMIntUGT,
+            IntPredicate::Uge => Self::LLVMIntUGE,
+            IntPredicate::Ult => Self::LLVMIntULT,
+            IntPredicate::Ule => Self::LLVMIntULE,
+            IntPredicate::Sgt => Self::LLVMIntSGT,
+            IntPredicate::Sge => Self::LLVMIntSGE,
+            IntPredicate::Slt => Self::LLVMIntSLT,
+            IntPredicate::Sle => Self::LLVMIntSLE,
+        }
+    }
+}
+
+/// Predicates of the `FCmp` instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RealPredicate {
+    /// Always false.
+    False,
+    /// Ordered and equal.
+    Oeq,
+    /// Ordered and greater than.
+    Ogt,
+    /// Ordered and greater or equal.
+    Oge,
+    /// Ordered and less than.
+    Olt,
+    /// Ordered and less or equal.
+    Ole,
+    /// Ordered and not equal.
+    One,
+    /// Ordered, neither operand is NaN.
+    Ord,
+    /// Unordered, either operand is NaN.
+    Uno,
+    /// Unordered or equal.
+    Ueq,
+    /// Unordered or greater than.
+    Ugt,
+    /// Unordered or greater or equal.
+    Uge,
+    /// Unordered or less than.
+    Ult,
+    /// Unordered or less or equal.
+    Ule,
+    /// Unordered or not equal.
+    Une,
+    /// Always true.
+    True,
+}
+
+impl From<RealPredicate> for LLVMRealPredicate {
+    fn from(value: RealPredicate) -> Self {
+        match value {
+            RealPredicate::False => Self::LLVMRealPredicateFalse,
+            RealPredicate::Oeq => Self::LLVMRealOEQ,
+            RealPredicate::Ogt => Self::LLVMRealOGT,
+            RealPredicate::Oge => Self::LLVMRealOGE,
+            RealPredicate::Olt => Self::LLVMRealOLT,
+            RealPredicate::Ole => Self::LLVMRealOLE,
+            RealPredicate::One => Self::LLVMRealONE,
+            RealPredicate::Ord => Self::LLVMRealORD,
+            RealPredicate::Uno => Self::LLVMRealUNO,
+            RealPredicate::Ueq => Self::LLVMRealUEQ,
+            RealPredicate::Ugt => Self::LLVMRealUGT,
+            RealPredicate::Uge => Self::LLVMRealUGE,
+            RealPredicate::Ult => Self::LLVMRealULT,
+            RealPredicate::Ule => Self::LLVMRealULE,
+            RealPredicate::Une => Self::LLVMRealUNE,
+            RealPredicate::True => Self::LLVMRealPredicateTrue,
+        }
+    }
+}
+
+/// A list of instruction opcodes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Opcode {
+    Ret,
+    Br,
+    Switch,
+    IndirectBr,
+    Invoke,
+    Unreachable,
+    CallBr,
+    FNeg,
+    Add,
+    FAdd,
+    Sub,
+    FSub,
+    Mul,
+    FMul,
+    UDiv,
+    SDiv,
+    FDiv,
+    URem,
+    SRem,
+    FRem,
+    Shl,
+    LShr,
+    AShr,
+    And,
+    Or,
+    Xor,
+    Alloca,
+    Load,
+    Store,
+    GetElementPtr,
+    Trunc,
+    ZExt,
+    SExt,
+    FPToUI,
+    FPToSI,
+    UIToFP,
+    SIToFP,
+    FPTrunc,
+    FPExt,
+    PtrToInt,
+    IntToPtr,
+    BitCast,
+    AddrSpaceCast,
+    ICmp,
+    FCmp,
+    Phi,
+    Call,
+    Select,
+    UserOp1,
+    UserOp2,
+    VAArg,
+    ExtractElement,
+    InsertElement,
+    ShuffleVector,
+    ExtractValue,
+    InsertValue,
+    Freeze,
+    Fence,
+    AtomicCmpXchg,
+    AtomicRMW,
+    Resume,
+    LandingPad,
+    CleanupRet,
+    CatchRet,
+    CatchPad,
+    CleanupPad,
+    CatchSwitch,
+}
+
+impl Opcode {
+    /// Is this the opcode of a binary operator, e.g. `Add` or `FDiv`.
+    pub fn is_binary_op(&self) -> bool {
+        matches!(
+            self,
+            Opcode::Add
+                | Opcode::FAdd
+                | Opcode::Sub
+                | Opcode::FSub
+                | Opcode::Mul
+                | Opcode::FMul
+                | Opcode::UDiv
+                | Opcode::SDiv
+                | Opcode::FDiv
+                | Opcode::URem
+                | Opcode::SRem
+                | Opcode::FRem
+                | Opcode::Shl
+                | Opcode::LShr
+                | Opcode::AShr
+                | Opcode::And
+                | Opcode::Or
+                | Opcode::Xor
+        )
+    }
+
+    /// Is this the opcode of a cast, e.g. `Trunc` or `BitCast`.
+    pub fn is_cast(&self) -> bool {
+        matches!(
+            self,
+            Opcode::Trunc
+                | Opcode::ZExt
+                | Opcode::SExt
+                | Opcode::FPToUI
+                | Opcode::FPToSI
+                | Opcode::UIToFP
+                | Opcode::SIToFP
+                | Opcode::FPTrunc
+                | Opcode::FPExt
+                | Opcode::PtrToInt
+                | Opcode::IntToPtr
+                | Opcode::BitCast
+                | Opcode::AddrSpaceCast
+        )
+    }
+}
+
+impl From<Opcode> for LLVMOpcode {
+    fn from(value: Opcode) -> Self {
+        match value {
+            Opcode::Ret => Self::LLVMRet,
+            Opcode::Br => Self::LLVMBr,
+            Opcode::Switch => Self::LLVMSwitch,
+            Opcode::IndirectBr => Self::LLVMIndirectBr,
+            Opcode::Invoke => Self::LLVMInvoke,
+            Opcode::Unreachable => Self::LLVMUnreachable,
+            Opcode::CallBr => Self::LLVMCallBr,
+            Opcode::FNeg => Self::LLVMFNeg,
+            Opcode::Add => Self::LLVMAdd,
+            Opcode::FAdd => Self::LLVMFAdd,
+            Opcode::Sub => Self::LLVMSub,
+            Opcode::FSub => Self::LLVMFSub,
+            Opcode::Mul => Self::LLVMMul,
+            Opcode::FMul => Self::LLVMFMul,
+            Opcode::UDiv => Self::LLVMUDiv,
+            Opcode::SDiv => Self::LLVMSDiv,
+            Opcode::FDiv => Self::LLVMFDiv,
+            Opcode::URem => Self::LLVMURem,
+            Opcode::SRem => Self::LLVMSRem,
+            Opcode::FRem => Self::LLVMFRem,
+            Opcode::Shl => Self::LLVMShl,
+            Opcode::LShr => Self::LLVMLShr,
+            Opcode::AShr => Self::LLVMAShr,
+            Opcode::And => Self::LLVMAnd,
+            Opcode::Or => Self::LLVMOr,
+            Opcode::Xor => Self::LLVMXor,
+            Opcode::Alloca => Self::LLVMAlloca,
+            Opcode::Load => Self::LLVMLoad,
+            Opcode::Store => Self::LLVMStore,
+            Opcode::GetElementPtr => Self::LLVMGetElementPtr,
+            Opcode::Trunc => Self::LLVMTrunc,
+            Opcode::ZExt => Self::LLVMZExt,
+            Opcode::SExt => Self::LLVMSExt,
+            Opcode::FPToUI => Self::LLVMFPToUI,
+            Opcode::FPToSI => Self::LLVMFPToSI,
+            Opcode::UIToFP => Self::LLVMUIToFP,
+            Opcode::SIToFP => Self::LLVMSIToFP,
+            Opcode::FPTrunc => Self::LLVMFPTrunc,
+            Opcode::FPExt => Self::LLVMFPExt,
+            Opcode::PtrToInt => Self::LLVMPtrToInt,
+            Opcode::IntToPtr => Self::LLVMIntToPtr,
+            Opcode::BitCast => Self::LLVMBitCast,
+            Opcode::AddrSpaceCast => Self::LLVMAddrSpaceCast,
+            Opcode::ICmp => Self::LLVMICmp,
+            Opcode::FCmp => Self::LLVMFCmp,
+            Opcode::Phi => Self::LLVMPHI,
+            Opcode::Call => Self::LLVMCall,
+            Opcode::Select => Self::LLVMSelect,
+            Opcode::UserOp1 => Self::LLVMUserOp1,
+            Opcode::UserOp2 => Self::LLVMUserOp2,
+            Opcode::VAArg => Self::LLVMVAArg,
+            Opcode::ExtractElement => Self::LLVMExtractElement,
+            Opcode::InsertElement => Self::LLVMInsertElement,
+            Opcode::ShuffleVector => Self::LLVMShuffleVector,
+            Opcode::ExtractValue => Self::LLVMExtractValue,
+            Opcode::InsertValue => Self::LLVMInsertValue,
+            Opcode::Freeze => Self::LLVMFreeze,
+            Opcode::Fence => Self::LLVMFence,
+            Opcode::AtomicCmpXchg => Self::LLVMAtomicCmpXchg,
+            Opcode::AtomicRMW => Self::LLVMAtomicRMW,
+            Opcode::Resume => Self::LLVMResume,
+            Opcode::LandingPad => Self::LLVMLandingPad,
+            Opcode::CleanupRet => Self::LLVMCleanupRet,
+            Opcode::CatchRet => Self::LLVMCatchRet,
+            Opcode::CatchPad => Self::LLVMCatchPad,
+            Opcode::CleanupPad => Self::LLVMCleanupPad,
+            Opcode::CatchSwitch => Self::LLVMCatchSwitch,
+        }
+    }
+}
+
+impl From<LLVMOpcode> for Opcode {
+    fn from(value: LLVMOpcode) -> Self {
+        match value {
+            LLVMOpcode::LLVMRet => Self::Ret,
+            LLVMOpcode::LLVMBr => Self::Br,
+            LLVMOpcode::LLVMSwitch => Self::Switch,
+            LLVMOpcode::LLVMIndirectBr => Self::IndirectBr,
+            LLVMOpcode::LLVMInvoke => Self::Invoke,
+            LLVMOpcode::LLVMUnreachable => Self::Unreachable,
+            LLVMOpcode::LLVMCallBr => Self::CallBr,
+            LLVMOpcode::LLVMFNeg => Self::FNeg,
+            LLVMOpcode::LLVMAdd => Self::Add,
+            LLVMOpcode::LLVMFAdd => Self::FAdd,
+            LLVMOpcode::LLVMSub => Self::Sub,
+            LLVMOpcode::LLVMFSub => Self::FSub,
+            LLVMOpcode::LLVMMul => Self::Mul,
+            LLVMOpcode::LLVMFMul => Self::FMul,
+            LLVMOpcode::LLVMUDiv => Self::UDiv,
+            LLVMOpcode::LLVMSDiv => Self::SDiv,
+            LLVMOpcode::LLVMFDiv => Self::FDiv,
+            LLVMOpcode::LLVMURem => Self::URem,
+            LLVMOpcode::LLVMSRem => Self::SRem,
+            LLVMOpcode::LLVMFRem => Self::FRem,
+            LLVMOpcode::LLVMShl => Self::Shl,
+            LLVMOpcode::LLVMLShr => Self::LShr,
+            LLVMOpcode::LLVMAShr => Self::AShr,
+            LLVMOpcode::LLVMAnd => Self::And,
+            LLVMOpcode::LLVMOr => Self::Or,
+            LLVMOpcode::LLVMXor => Self::Xor,
+            LLVMOpcode::LLVMAlloca => Self::Alloca,
+            LLVMOpcode::LLVMLoad => Self::Load,
+            LLVMOpcode::LLVMStore => Self::Store,
+            LLVMOpcode::LLVMGetElementPtr => Self::GetElementPtr,
+            LLVMOpcode::LLVMTrunc => Self::Trunc,
+            LLVMOpcode::LLVMZExt => Self::ZExt,
+            LLVMOpcode::LLVMSExt => Self::SExt,
+            LLVMOpcode::LLVMFPToUI => Self::FPToUI,
+            LLVMOpcode::LLVMFPToSI => Self::FPToSI,
+            LLVMOpcode::LLVMUIToFP => Self::UIToFP,
+            LLVMOpcode::LLVMSIToFP => Self::SIToFP,
+            LLVMOpcode::LLVMFPTrunc => Self::FPTrunc,
+            LLVMOpcode::LLVMFPExt => Self::FPExt,
+            LLVMOpcode::LLVMPtrToInt => Self::PtrToInt,
+            LLVMOpcode::LLVMIntToPtr => Self::IntToPtr,
+            LLVMOpcode::LLVMBitCast => Self::BitCast,
+            LLVMOpcode::LLVMAddrSpaceCast => Self::AddrSpaceCast,
+            LLVMOpcode::LLVMICmp => Self::ICmp,
+            LLVMOpcode::LLVMFCmp => Self::FCmp,
+            LLVMOpcode::LLVMPHI => Self::Phi,
+            LLVMOpcode::LLVMCall => Self::Call,
+            LLVMOpcode::LLVMSelect => Self::Select,
+            LLVMOpcode::LLVMUserOp1 => Self::UserOp1,
+            LLVMOpcode::LLVMUserOp2 => Self::UserOp2,
+            LLVMOpcode::LLVMVAArg => Self::VAArg,
+            LLVMOpcode::LLVMExtractElement => Self::ExtractElement,
+            LLVMOpcode::LLVMInsertElement => Self::InsertElement,
+            LLVMOpcode::LLVMShuffleVector => Self::ShuffleVector,
+            LLVMOpcode::LLVMExtractValue => Self::ExtractValue,
+            LLVMOpcode::LLVMInsertValue => Self::InsertValue,
+            LLVMOpcode::LLVMFreeze => Self::Freeze,
+            LLVMOpcode::LLVMFence => Self::Fence,
+            LLVMOpcode::LLVMAtomicCmpXchg => Self::AtomicCmpXchg,
+            LLVMOpcode::LLVMAtomicRMW => Self::AtomicRMW,
+            LLVMOpcode::LLVMResume => Self::Resume,
+            LLVMOpcode::LLVMLandingPad => Self::LandingPad,
+            LLVMOpcode::LLVMCleanupRet => Self::CleanupRet,
+            LLVMOpcode::LLVMCatchRet => Self::CatchRet,
+            LLVMOpcode::LLVMCatchPad => Self::CatchPad,
+            LLVMOpcode::LLVMCleanupPad => Self::CleanupPad,
+            LLVMOpcode::LLVMCatchSwitch => Self::CatchSwitch,
+        }
+    }
+}
@@ -1,29 +1,39 @@
-use std::ptr::null_mut;
+use std::{ffi::c_char, ptr::null_mut};
 
 use llvm_sys::{
+    bit_reader,
     core::{
-        LLVMAppendBasicBlockInContext, LLVMArrayType2, LLVMBFloatTypeInContext, LLVMContextCreate,
+        LLVMAppendBasicBlockInContext, LLVMArrayType2, LLVMBFloatTypeInContext,
+        LLVMConstStringInContext, LLVMConstStructInContext, LLVMConstVector, LLVMContextCreate,
         LLVMContextDispose, LLVMCreateBuilderInContext, LLVMDoubleTypeInContext,
         LLVMFP128TypeInContext, LLVMFloatTypeInContext, LLVMFunctionType, LLVMHalfTypeInContext,
         LLVMInt128TypeInContext, LLVMInt16TypeInContext, LLVMInt1TypeInContext,
         LLVMInt32TypeInContext, LLVMInt64TypeInContext, LLVMInt8TypeInContext,
-        LLVMIntTypeInContext, LLVMModuleCreateWithNameInContext, LLVMPPCFP128TypeInContext,
-        LLVMPointerTypeInContext, LLVMStructCreateNamed, LLVMStructTypeInContext,
-        LLVMVoidTypeInContext, LLVMX86FP80TypeInContext,
+        LLVMIntTypeInContext, LLVMLabelTypeInContext, LLVMMetadataTypeInContext,
+        LLVMModuleCreateWithNameInContext, LLVMPPCFP128TypeInContext, LLVMPointerTypeInContext,
+        LLVMScalableVectorType, LLVMStructCreateNamed, LLVMStructTypeInContext,
+        LLVMTokenTypeInContext, LLVMVectorType, LLVMVoidTypeInContext, LLVMX86AMXTypeInContext,
+        LLVMX86FP80TypeInContext,
     },
-    prelude::{LLVMContextRef, LLVMTypeRef},
+    ir_reader::LLVMParseIRInContext,
+    prelude::{LLVMContextRef, LLVMTypeRef, LLVMValueRef},
 };
 
 use crate::{
     basic_block::BasicBlock,
     builder::Builder,
+    error::{Error, ParseDiagnostic},
+    memory_buffer::MemoryBuffer,
     module::Module,
-    ty::{AddressSpace, Type},
-    util::string_to_cstring,
-    value::Value,
+    ty::{
+        AddressSpace, ArrayType, FloatType, FunctionType, IntType, PointerType, StructType, Type,
+        VectorType,
+    },
+    util::{string_to_cstring, LlvmString},
+    value::{FunctionValue, Value},
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct Context(LLVMContextRef);
 
 impl Context {
@@ -33,154 +43,338 @@ impl Context {
     }
 
     /// Create a new [`Context`].
-    pub fn create() -> Self {
-        unsafe {
-            let pointer = LLVMContextCreate();
-            assert_ne!(pointer, null_mut(), "context pointer is null");
-            Self(pointer)
+    pub fn create() -> Result<Self, Error> {
+        let pointer = unsafe { LLVMContextCreate() };
+
+        if pointer.is_null() {
+            return Err(Error::NullHandle("context"));
         }
+
+        Ok(Self(pointer))
     }
 
     /// Create a new [`Module`] in the current [`Context`].
-    pub fn create_module<S: ToString>(&self, name: S) -> Module {
+    pub fn create_module<S: ToString>(&self, name: S) -> Result<Module<'_>, Error> {
         let name = string_to_cstring(name.to_string());
 
         unsafe { Module::new(LLVMModuleCreateWithNameInContext(name.as_ptr(), self.get())) }
     }
 
     /// Create a new [`Builder`] in the current [`Context`].
-    pub fn create_builder(&self) -> Builder {
+    pub fn create_builder(&self) -> Result<Builder<'_>, Error> {
         unsafe { Builder::new(LLVMCreateBuilderInContext(self.get())) }
     }
 
+    /// Parse textual IR into a new [`Module`].
+    pub fn parse_ir(&self, source: &str) -> Result<Module<'_>, Error> {
+        self.parse_ir_buffer(MemoryBuffer::from_bytes(source.as_bytes(), "<string>")?)
+    }
+
+    /// Parse a textual IR file into a new [`Module`].
+    pub fn parse_ir_file<S: ToString>(&self, path: S) -> Result<Module<'_>, Error> {
+        self.parse_ir_buffer(MemoryBuffer::from_file(path)?)
+    }
+
+    /// Parse textual IR held in a [`MemoryBuffer`] into a new [`Module`].
+    pub fn parse_ir_buffer(&self, buffer: MemoryBuffer) -> Result<Module<'_>, Error> {
+        let mut module = null_mut();
+        let mut message = null_mut();
+
+        // LLVMParseIRInContext always takes ownership of the buffer.
+        let failed = unsafe {
+            LLVMParseIRInContext(self.get(), buffer.into_raw(), &mut module, &mut message) != 0
+        };
+        let message = unsafe { LlvmString::new(message) };
+
+        if failed {
+            return Err(Error::Parse(match message {
+                Some(message) => ParseDiagnostic::from_llvm_message(&message.to_string()),
+                None => ParseDiagnostic::new("failed to parse IR"),
+            }));
+        }
+
+        Module::new(module)
+    }
+
+    /// Parse bitcode into a new [`Module`].
+    pub fn parse_bitcode(&self, bytes: &[u8]) -> Result<Module<'_>, Error> {
+        let buffer = MemoryBuffer::from_bytes(bytes, "<bitcode>")?;
+        let mut module = null_mut();
+        let mut message = null_mut();
+
+        // The non-deprecated `LLVMParseBitcodeInContext2` reports errors to the context
+        // diagnostic handler, which exits the process when none is installed.
+        #[allow(deprecated)]
+        let failed = unsafe {
+            bit_reader::LLVMParseBitcodeInContext(
+                self.get(),
+                buffer.get(),
+                &mut module,
+                &mut message,
+            ) != 0
+        };
+
+        if failed {
+            return Err(bitcode_error(message));
+        }
+
+        Module::new(module)
+    }
+
+    /// Lazily load bitcode into a new [`Module`], function bodies are materialized on demand.
+    pub fn parse_bitcode_lazy(&self, bytes: &[u8]) -> Result<Module<'_>, Error> {
+        let buffer = MemoryBuffer::from_bytes(bytes, "<bitcode>")?;
+        let mut module = null_mut();
+        let mut message = null_mut();
+
+        // See `parse_bitcode` for why the deprecated variant is used.
+        #[allow(deprecated)]
+        let failed = unsafe {
+            bit_reader::LLVMGetBitcodeModuleInContext(
+                self.get(),
+                buffer.get(),
+                &mut module,
+                &mut message,
+            ) != 0
+        };
+
+        if failed {
+            return Err(bitcode_error(message));
+        }
+
+        // The lazily loaded module now owns the buffer.
+        buffer.into_raw();
+
+        Module::new(module)
+    }
+
     /// Create a new Void [`Type`].
-    pub fn create_void_type(&self) -> Type {
+    pub fn create_void_type(&self) -> Type<'_> {
         unsafe { Type::new(LLVMVoidTypeInContext(self.get())) }
     }
 
     /// Create a new 1-bit Integer [`Type`].
-    pub fn create_i1_type(&self) -> Type {
-        unsafe { Type::new(LLVMInt1TypeInContext(self.get())) }
+    pub fn create_i1_type(&self) -> IntType<'_> {
+        unsafe { IntType::new(Type::new(LLVMInt1TypeInContext(self.get()))) }
     }
 
     /// Create a new 8-bit Integer [`Type`].
-    pub fn create_i8_type(&self) -> Type {
-        unsafe { Type::new(LLVMInt8TypeInContext(self.get())) }
+    pub fn create_i8_type(&self) -> IntType<'_> {
+        unsafe { IntType::new(Type::new(LLVMInt8TypeInContext(self.get()))) }
     }
 
     /// Create a new 16-bit Integer [`Type`].
-    pub fn create_i16_type(&self) -> Type {
-        unsafe { Type::new(LLVMInt16TypeInContext(self.get())) }
+    pub fn create_i16_type(&self) -> IntType<'_> {
+        unsafe { IntType::new(Type::new(LLVMInt16TypeInContext(self.get()))) }
     }
 
     /// Create a new 32-bit Integer [`Type`].
-    pub fn create_i32_type(&self) -> Type {
-        unsafe { Type::new(LLVMInt32TypeInContext(self.get())) }
+    pub fn create_i32_type(&self) -> IntType<'_> {
+        unsafe { IntType::new(Type::new(LLVMInt32TypeInContext(self.get()))) }
     }
 
     /// Create a new 64-bit Integer [`Type`].
-    pub fn create_i64_type(&self) -> Type {
-        unsafe { Type::new(LLVMInt64TypeInContext(self.get())) }
+    pub fn create_i64_type(&self) -> IntType<'_> {
+        unsafe { IntType::new(Type::new(LLVMInt64TypeInContext(self.get()))) }
     }
 
     /// Create a new 128-bit Integer [`Type`].
-    pub fn create_i128_type(&self) -> Type {
-        unsafe { Type::new(LLVMInt128TypeInContext(self.get())) }
+    pub fn create_i128_type(&self) -> IntType<'_> {
+        unsafe { IntType::new(Type::new(LLVMInt128TypeInContext(self.get()))) }
     }
 
     /// Create a new n-bit Integer [`Type`].
-    pub fn create_int_type(&self, n: u32) -> Type {
-        unsafe { Type::new(LLVMIntTypeInContext(self.get(), n)) }
+    pub fn create_int_type(&self, n: u32) -> IntType<'_> {
+        unsafe { IntType::new(Type::new(LLVMIntTypeInContext(self.get(), n))) }
     }
 
     /// Create a new 16-bit brain Float [`Type`].
-    pub fn create_bf16_type(&self) -> Type {
-        unsafe { Type::new(LLVMBFloatTypeInContext(self.get())) }
+    pub fn create_bf16_type(&self) -> FloatType<'_> {
+        unsafe { FloatType::new(Type::new(LLVMBFloatTypeInContext(self.get()))) }
     }
 
     /// Create a new 16-bit Float [`Type`].
-    pub fn create_f16_type(&self) -> Type {
-        unsafe { Type::new(LLVMHalfTypeInContext(self.get())) }
+    pub fn create_f16_type(&self) -> FloatType<'_> {
+        unsafe { FloatType::new(Type::new(LLVMHalfTypeInContext(self.get()))) }
     }
 
     /// Create a new 32-bit Float [`Type`].
-    pub fn create_f32_type(&self) -> Type {
-        unsafe { Type::new(LLVMFloatTypeInContext(self.get())) }
+    pub fn create_f32_type(&self) -> FloatType<'_> {
+        unsafe { FloatType::new(Type::new(LLVMFloatTypeInContext(self.get()))) }
     }
 
     /// Create a new 64-bit Float [`Type`].
-    pub fn create_f64_type(&self) -> Type {
-        unsafe { Type::new(LLVMDoubleTypeInContext(self.get())) }
+    pub fn create_f64_type(&self) -> FloatType<'_> {
+        unsafe { FloatType::new(Type::new(LLVMDoubleTypeInContext(self.get()))) }
     }
 
     /// Create a new 80-bit Float [`Type`] on x86 only.
-    pub fn create_x86_f80_type(&self) -> Type {
-        unsafe { Type::new(LLVMX86FP80TypeInContext(self.get())) }
+    pub fn create_x86_f80_type(&self) -> FloatType<'_> {
+        unsafe { FloatType::new(Type::new(LLVMX86FP80TypeInContext(self.get()))) }
     }
 
     /// Create a new 128-bit Float [`Type`].
-    pub fn create_f128_type(&self) -> Type {
-        unsafe { Type::new(LLVMFP128TypeInContext(self.get())) }
+    pub fn create_f128_type(&self) -> FloatType<'_> {
+        unsafe { FloatType::new(Type::new(LLVMFP128TypeInContext(self.get()))) }
     }
 
     /// Create a new 128-bit Float [`Type`], split into 2x 64-bit on PowerPC only.
-    pub fn create_ppc_f128_type(&self) -> Type {
-        unsafe { Type::new(LLVMPPCFP128TypeInContext(self.get())) }
+    pub fn create_ppc_f128_type(&self) -> FloatType<'_> {
+        unsafe { FloatType::new(Type::new(LLVMPPCFP128TypeInContext(self.get()))) }
+    }
+
+    /// Create a new Label [`Type`].
+    pub fn create_label_type(&self) -> Type<'_> {
+        unsafe { Type::new(LLVMLabelTypeInContext(self.get())) }
+    }
+
+    /// Create a new Metadata [`Type`].
+    pub fn create_metadata_type(&self) -> Type<'_> {
+        unsafe { Type::new(LLVMMetadataTypeInContext(self.get())) }
+    }
+
+    /// Create a new Token [`Type`].
+    pub fn create_token_type(&self) -> Type<'_> {
+        unsafe { Type::new(LLVMTokenTypeInContext(self.get())) }
+    }
+
+    /// Create a new x86 AMX tile [`Type`] on x86 only.
+    pub fn create_x86_amx_type(&self) -> Type<'_> {
+        unsafe { Type::new(LLVMX86AMXTypeInContext(self.get())) }
     }
 
     /// Create a new Pointer [`Type`].
-    pub fn create_ptr_type(&self, address_space: AddressSpace) -> Type {
-        unsafe { Type::new(LLVMPointerTypeInContext(self.get(), address_space as u32)) }
+    pub fn create_ptr_type(&self, address_space: AddressSpace) -> PointerType<'_> {
+        unsafe {
+            PointerType::new(Type::new(LLVMPointerTypeInContext(
+                self.get(),
+                address_space as u32,
+            )))
+        }
     }
 
     /// Create a new Function [`Type`].
-    pub fn create_func_type(&self, return_ty: &Type, param_tys: &[Type], is_var_arg: bool) -> Type {
+    pub fn create_func_type<'ctx>(
+        &'ctx self,
+        return_ty: &Type<'ctx>,
+        param_tys: &[Type<'ctx>],
+        is_var_arg: bool,
+    ) -> FunctionType<'ctx> {
         let mut param_tys = param_tys
             .iter()
             .map(|ty| ty.get())
             .collect::<Vec<LLVMTypeRef>>();
 
         unsafe {
-            Type::new(LLVMFunctionType(
+            FunctionType::new(Type::new(LLVMFunctionType(
                 return_ty.get(),
                 param_tys.as_mut_ptr(),
                 param_tys.len() as u32,
                 is_var_arg as i32,
-            ))
+            )))
         }
     }
 
     /// Create a new Array [`Type`].
-    pub fn create_array_type(&self, element_ty: &Type, size: u64) -> Type {
-        unsafe { Type::new(LLVMArrayType2(element_ty.get(), size)) }
+    pub fn create_array_type<'ctx>(
+        &'ctx self,
+        element_ty: &Type<'ctx>,
+        size: u64,
+    ) -> ArrayType<'ctx> {
+        unsafe { ArrayType::new(Type::new(LLVMArrayType2(element_ty.get(), size))) }
+    }
+
+    /// Create a new fixed Vector [`Type`] of `size` elements.
+    pub fn create_vector_type<'ctx>(
+        &'ctx self,
+        element_ty: &Type<'ctx>,
+        size: u32,
+    ) -> VectorType<'ctx> {
+        unsafe { VectorType::new(Type::new(LLVMVectorType(element_ty.get(), size))) }
+    }
+
+    /// Create a new scalable Vector [`Type`] of a runtime multiple of `size` elements.
+    pub fn create_scalable_vector_type<'ctx>(
+        &'ctx self,
+        element_ty: &Type<'ctx>,
+        size: u32,
+    ) -> VectorType<'ctx> {
+        unsafe { VectorType::new(Type::new(LLVMScalableVectorType(element_ty.get(), size))) }
     }
 
     /// Create a new Struct [`Type`].
-    pub fn create_struct_type(&self, element_tys: &[Type], is_packed: bool) -> Type {
+    pub fn create_struct_type<'ctx>(
+        &'ctx self,
+        element_tys: &[Type<'ctx>],
+        is_packed: bool,
+    ) -> StructType<'ctx> {
         let mut element_tys = element_tys
             .iter()
             .map(|elem| elem.get())
             .collect::<Vec<LLVMTypeRef>>();
 
         unsafe {
-            Type::new(LLVMStructTypeInContext(
+            StructType::new(Type::new(LLVMStructTypeInContext(
                 self.get(),
                 element_tys.as_mut_ptr(),
                 element_tys.len() as u32,
                 is_packed as i32,
-            ))
+            )))
         }
     }
 
     /// Create a named Struct [`Type`].
-    pub fn create_named_struct_type<S: ToString>(&self, name: S) -> Type {
+    pub fn create_named_struct_type<S: ToString>(&self, name: S) -> StructType<'_> {
         let name = string_to_cstring(name.to_string());
 
-        unsafe { Type::new(LLVMStructCreateNamed(self.get(), name.as_ptr())) }
+        unsafe { StructType::new(Type::new(LLVMStructCreateNamed(self.get(), name.as_ptr()))) }
+    }
+
+    /// Create a constant literal Struct from [`Value`]s.
+    pub fn const_struct<'ctx>(&'ctx self, values: &[Value<'ctx>], is_packed: bool) -> Value<'ctx> {
+        let mut values = values
+            .iter()
+            .map(|value| value.get())
+            .collect::<Vec<LLVMValueRef>>();
+
+        unsafe {
+            Value::new(LLVMConstStructInContext(
+                self.get(),
+                values.as_mut_ptr(),
+                values.len() as u32,
+                is_packed as i32,
+            ))
+        }
+    }
+
+    /// Create a constant Vector from scalar [`Value`]s.
+    pub fn const_vector<'ctx>(&'ctx self, values: &[Value<'ctx>]) -> Value<'ctx> {
+        let mut values = values
+            .iter()
+            .map(|value| value.get())
+            .collect::<Vec<LLVMValueRef>>();
+
+        unsafe { Value::new(LLVMConstVector(values.as_mut_ptr(), values.len() as u32)) }
+    }
+
+    /// Create a constant `i8` Array holding `bytes`, optionally followed by a nul byte.
+    pub fn const_string(&self, bytes: &[u8], null_terminate: bool) -> Value<'_> {
+        unsafe {
+            Value::new(LLVMConstStringInContext(
+                self.get(),
+                bytes.as_ptr() as *const _,
+                bytes.len() as u32,
+                !null_terminate as i32,
+            ))
+        }
     }
 
     /// Appends a [`BasicBlock`] to a function.
-    pub fn append_basic_block<S: ToString>(&self, func: &Value, name: S) -> BasicBlock {
+    pub fn append_basic_block<'ctx, S: ToString>(
+        &'ctx self,
+        func: &FunctionValue<'ctx>,
+        name: S,
+    ) -> BasicBlock<'ctx> {
         let name = string_to_cstring(name.to_string());
 
         unsafe {
@@ -191,9 +385,20 @@ impl Context {
             ))
         }
     }
+}
 
-    /// Dispose this [`Context`] and free its memory.
-    pub fn dispose(self) {
+impl Drop for Context {
+    fn drop(&mut self) {
         unsafe { LLVMContextDispose(self.get()) }
     }
 }
+
+/// Build the [`Error`] for a failed bitcode read from the message LLVM allocated.
+fn bitcode_error(message: *mut c_char) -> Error {
+    let message = unsafe { LlvmString::new(message) };
+
+    Error::Parse(match message {
+        Some(message) => ParseDiagnostic::new(message),
+        None => ParseDiagnostic::new("invalid bitcode"),
+    })
+}
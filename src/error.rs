@@ -0,0 +1,96 @@
+use std::{fmt, io};
+
+/// Errors reported by the fallible APIs of this crate.
+#[derive(Debug)]
+pub enum Error {
+    /// The verifier rejected a module or function.
+    Verification(String),
+    /// No target matches the requested name or triple.
+    TargetLookup(String),
+    /// Code generation failed to emit the requested output.
+    Emission(String),
+    /// An I/O operation failed.
+    Io(io::Error),
+    /// Textual IR or bitcode could not be parsed.
+    Parse(ParseDiagnostic),
+    /// LLVM returned a null handle for the named object.
+    NullHandle(&'static str),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Verification(message) => write!(f, "verification failed: {message}"),
+            Error::TargetLookup(message) => write!(f, "target lookup failed: {message}"),
+            Error::Emission(message) => write!(f, "emission failed: {message}"),
+            Error::Io(error) => write!(f, "i/o error: {error}"),
+            Error::Parse(diagnostic) => write!(f, "parse failed: {diagnostic}"),
+            Error::NullHandle(name) => write!(f, "{name} pointer is null"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(value: io::Error) -> Self {
+        Error::Io(value)
+    }
+}
+
+/// A diagnostic reported while parsing textual IR or bitcode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDiagnostic {
+    /// The error message, without location prefix.
+    pub message: String,
+    /// The 1-based line the error was reported at, if known.
+    pub line: Option<u32>,
+    /// The 1-based column the error was reported at, if known.
+    pub column: Option<u32>,
+}
+
+impl ParseDiagnostic {
+    /// Create a [`ParseDiagnostic`] without location information.
+    pub(crate) fn new<S: ToString>(message: S) -> Self {
+        Self {
+            message: message.to_string(),
+            line: None,
+            column: None,
+        }
+    }
+
+    /// Parse a diagnostic printed by LLVM in the `name:line:column: error: message` form.
+    pub(crate) fn from_llvm_message(text: &str) -> Self {
+        let first_line = text.lines().next().unwrap_or_default();
+
+        let Some((location, message)) = first_line.split_once(": error: ") else {
+            return Self::new(first_line.trim());
+        };
+
+        let mut parts = location.rsplitn(3, ':');
+        let column = parts.next().and_then(|column| column.parse().ok());
+        let line = parts.next().and_then(|line| line.parse().ok());
+
+        Self {
+            message: message.trim().to_string(),
+            line,
+            column,
+        }
+    }
+}
+
+impl fmt::Display for ParseDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "{line}:{column}: {}", self.message),
+            _ => f.write_str(&self.message),
+        }
+    }
+}
@@ -0,0 +1,374 @@
+use llvm_sys::{
+    core::{
+        LLVMDeleteGlobal, LLVMGetAlignment, LLVMGetDLLStorageClass, LLVMGetInitializer,
+        LLVMGetLinkage, LLVMGetSection, LLVMGetThreadLocalMode, LLVMGetUnnamedAddress,
+        LLVMGetVisibility, LLVMIsAGlobalValue, LLVMIsAGlobalVariable, LLVMIsGlobalConstant,
+        LLVMSetAlignment, LLVMSetDLLStorageClass, LLVMSetGlobalConstant, LLVMSetInitializer,
+        LLVMSetLinkage, LLVMSetSection, LLVMSetThreadLocalMode, LLVMSetUnnamedAddress,
+        LLVMSetVisibility,
+    },
+    LLVMDLLStorageClass, LLVMLinkage, LLVMThreadLocalMode, LLVMUnnamedAddr, LLVMVisibility,
+};
+
+use crate::{
+    util::{borrowed_to_string, string_to_cstring},
+    value::{value_wrapper, PointerValue, Value},
+};
+
+/// A global variable of a [`Module`](crate::module::Module), linkage and other properties
+/// shared with functions are set through [`GlobalVariable::as_global_value`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GlobalVariable<'ctx>(Value<'ctx>);
+
+impl<'ctx> GlobalVariable<'ctx> {
+    /// Create a new [`GlobalVariable`] from a global variable [`Value`].
+    pub(crate) fn new(value: Value<'ctx>) -> Self {
+        Self(value)
+    }
+
+    /// Get the [`GlobalVariable`] for a [`Value`], if it is a global variable.
+    pub fn from_value(value: Value<'ctx>) -> Option<Self> {
+        if unsafe { LLVMIsAGlobalVariable(value.get()) }.is_null() {
+            return None;
+        }
+
+        Some(Self(value))
+    }
+
+    /// Get the underlying [`Value`].
+    pub fn as_value(&self) -> Value<'ctx> {
+        self.0
+    }
+
+    /// Get this global variable as a [`GlobalValue`].
+    pub fn as_global_value(&self) -> GlobalValue<'ctx> {
+        GlobalValue::new(self.0)
+    }
+
+    /// Get the address of this global variable.
+    pub fn as_pointer_value(&self) -> PointerValue<'ctx> {
+        PointerValue::new(self.0)
+    }
+
+    /// Set the constant initializer.
+    pub fn set_initializer(&self, value: &Value<'ctx>) {
+        unsafe { LLVMSetInitializer(self.0.get(), value.get()) }
+    }
+
+    /// Get the constant initializer, declarations have none.
+    pub fn get_initializer(&self) -> Option<Value<'ctx>> {
+        let value = unsafe { LLVMGetInitializer(self.0.get()) };
+
+        if value.is_null() {
+            return None;
+        }
+
+        Some(Value::new(value))
+    }
+
+    /// Mark the global as constant, its memory is never written to.
+    pub fn set_constant(&self, constant: bool) {
+        unsafe { LLVMSetGlobalConstant(self.0.get(), constant as i32) }
+    }
+
+    /// Is the global constant.
+    pub fn is_constant(&self) -> bool {
+        unsafe { LLVMIsGlobalConstant(self.0.get()) == 1 }
+    }
+
+    /// Set the [`ThreadLocalMode`].
+    pub fn set_thread_local_mode(&self, mode: ThreadLocalMode) {
+        unsafe { LLVMSetThreadLocalMode(self.0.get(), mode.into()) }
+    }
+
+    /// Get the [`ThreadLocalMode`].
+    pub fn get_thread_local_mode(&self) -> ThreadLocalMode {
+        unsafe { LLVMGetThreadLocalMode(self.0.get()) }.into()
+    }
+
+    /// Remove this global from its module and delete it.
+    ///
+    /// # Safety
+    ///
+    /// The global must have no remaining uses, and since handles are [`Copy`], no other copy of
+    /// this one may be used afterwards.
+    pub unsafe fn delete(self) {
+        unsafe { LLVMDeleteGlobal(self.0.get()) }
+    }
+}
+
+value_wrapper!(GlobalVariable);
+
+/// A function or global variable of a [`Module`](crate::module::Module).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GlobalValue<'ctx>(Value<'ctx>);
+
+impl<'ctx> GlobalValue<'ctx> {
+    /// Create a new [`GlobalValue`] from a global [`Value`].
+    pub(crate) fn new(value: Value<'ctx>) -> Self {
+        Self(value)
+    }
+
+    /// Get the [`GlobalValue`] for a [`Value`], if it is a function or global variable.
+    pub fn from_value(value: Value<'ctx>) -> Option<Self> {
+        if unsafe { LLVMIsAGlobalValue(value.get()) }.is_null() {
+            return None;
+        }
+
+        Some(Self(value))
+    }
+
+    /// Get the underlying [`Value`].
+    pub fn as_value(&self) -> Value<'ctx> {
+        self.0
+    }
+
+    /// Get the address of this global.
+    pub fn as_pointer_value(&self) -> PointerValue<'ctx> {
+        PointerValue::new(self.0)
+    }
+
+    /// Set the alignment in bytes, `0` leaves it to the target.
+    pub fn set_alignment(&self, alignment: u32) {
+        unsafe { LLVMSetAlignment(self.0.get(), alignment) }
+    }
+
+    /// Get the alignment in bytes.
+    pub fn get_alignment(&self) -> u32 {
+        unsafe { LLVMGetAlignment(self.0.get()) }
+    }
+
+    /// Set the section the global is placed in.
+    pub fn set_section<S: ToString>(&self, section: S) {
+        let section = string_to_cstring(section.to_string());
+
+        unsafe { LLVMSetSection(self.0.get(), section.as_ptr()) }
+    }
+
+    /// Get the section the global is placed in.
+    pub fn get_section(&self) -> Option<String> {
+        unsafe { borrowed_to_string(LLVMGetSection(self.0.get())) }
+    }
+
+    /// Set the [`Linkage`].
+    pub fn set_linkage(&self, linkage: Linkage) {
+        unsafe { LLVMSetLinkage(self.0.get(), linkage.into()) }
+    }
+
+    /// Get the [`Linkage`].
+    pub fn get_linkage(&self) -> Linkage {
+        unsafe { LLVMGetLinkage(self.0.get()) }.into()
+    }
+
+    /// Set the [`Visibility`].
+    pub fn set_visibility(&self, visibility: Visibility) {
+        unsafe { LLVMSetVisibility(self.0.get(), visibility.into()) }
+    }
+
+    /// Get the [`Visibility`].
+    pub fn get_visibility(&self) -> Visibility {
+        unsafe { LLVMGetVisibility(self.0.get()) }.into()
+    }
+
+    /// Set the [`DllStorageClass`].
+    pub fn set_dll_storage_class(&self, class: DllStorageClass) {
+        unsafe { LLVMSetDLLStorageClass(self.0.get(), class.into()) }
+    }
+
+    /// Get the [`DllStorageClass`].
+    pub fn get_dll_storage_class(&self) -> DllStorageClass {
+        unsafe { LLVMGetDLLStorageClass(self.0.get()) }.into()
+    }
+
+    /// Set the [`UnnamedAddr`].
+    pub fn set_unnamed_addr(&self, unnamed_addr: UnnamedAddr) {
+        unsafe { LLVMSetUnnamedAddress(self.0.get(), unnamed_addr.into()) }
+    }
+
+    /// Get the [`UnnamedAddr`].
+    pub fn get_unnamed_addr(&self) -> UnnamedAddr {
+        unsafe { LLVMGetUnnamedAddress(self.0.get()) }.into()
+    }
+}
+
+value_wrapper!(GlobalValue);
+
+/// How a global value is linked with other modules.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Linkage {
+    External,
+    AvailableExternally,
+    LinkOnceAny,
+    LinkOnceOdr,
+    WeakAny,
+    WeakOdr,
+    Appending,
+    Internal,
+    Private,
+    ExternalWeak,
+    Common,
+}
+
+impl From<Linkage> for LLVMLinkage {
+    fn from(value: Linkage) -> Self {
+        match value {
+            Linkage::External => Self::LLVMExternalLinkage,
+            Linkage::AvailableExternally => Self::LLVMAvailableExternallyLinkage,
+            Linkage::LinkOnceAny => Self::LLVMLinkOnceAnyLinkage,
+            Linkage::LinkOnceOdr => Self::LLVMLinkOnceODRLinkage,
+            Linkage::WeakAny => Self::LLVMWeakAnyLinkage,
+            Linkage::WeakOdr => Self::LLVMWeakODRLinkage,
+            Linkage::Appending => Self::LLVMAppendingLinkage,
+            Linkage::Internal => Self::LLVMInternalLinkage,
+            Linkage::Private => Self::LLVMPrivateLinkage,
+            Linkage::ExternalWeak => Self::LLVMExternalWeakLinkage,
+            Linkage::Common => Self::LLVMCommonLinkage,
+        }
+    }
+}
+
+impl From<LLVMLinkage> for Linkage {
+    fn from(value: LLVMLinkage) -> Self {
+        match value {
+            LLVMLinkage::LLVMExternalLinkage
+            | LLVMLinkage::LLVMDLLImportLinkage
+            | LLVMLinkage::LLVMDLLExportLinkage
+            | LLVMLinkage::LLVMGhostLinkage => Self::External,
+            LLVMLinkage::LLVMAvailableExternallyLinkage => Self::AvailableExternally,
+            LLVMLinkage::LLVMLinkOnceAnyLinkage => Self::LinkOnceAny,
+            LLVMLinkage::LLVMLinkOnceODRLinkage | LLVMLinkage::LLVMLinkOnceODRAutoHideLinkage => {
+                Self::LinkOnceOdr
+            }
+            LLVMLinkage::LLVMWeakAnyLinkage => Self::WeakAny,
+            LLVMLinkage::LLVMWeakODRLinkage => Self::WeakOdr,
+            LLVMLinkage::LLVMAppendingLinkage => Self::Appending,
+            LLVMLinkage::LLVMInternalLinkage => Self::Internal,
+            LLVMLinkage::LLVMPrivateLinkage
+            | LLVMLinkage::LLVMLinkerPrivateLinkage
+            | LLVMLinkage::LLVMLinkerPrivateWeakLinkage => Self::Private,
+            LLVMLinkage::LLVMExternalWeakLinkage => Self::ExternalWeak,
+            LLVMLinkage::LLVMCommonLinkage => Self::Common,
+        }
+    }
+}
+
+/// Symbol visibility of a global value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Visibility {
+    Default,
+    Hidden,
+    Protected,
+}
+
+impl From<Visibility> for LLVMVisibility {
+    fn from(value: Visibility) -> Self {
+        match value {
+            Visibility::Default => Self::LLVMDefaultVisibility,
+            Visibility::Hidden => Self::LLVMHiddenVisibility,
+            Visibility::Protected => Self::LLVMProtectedVisibility,
+        }
+    }
+}
+
+impl From<LLVMVisibility> for Visibility {
+    fn from(value: LLVMVisibility) -> Self {
+        match value {
+            LLVMVisibility::LLVMDefaultVisibility => Self::Default,
+            LLVMVisibility::LLVMHiddenVisibility => Self::Hidden,
+            LLVMVisibility::LLVMProtectedVisibility => Self::Protected,
+        }
+    }
+}
+
+/// Windows DLL storage class of a global value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DllStorageClass {
+    Default,
+    Import,
+    Export,
+}
+
+impl From<DllStorageClass> for LLVMDLLStorageClass {
+    fn from(value: DllStorageClass) -> Self {
+        match value {
+            DllStorageClass::Default => Self::LLVMDefaultStorageClass,
+            DllStorageClass::Import => Self::LLVMDLLImportStorageClass,
+            DllStorageClass::Export => Self::LLVMDLLExportStorageClass,
+        }
+    }
+}
+
+impl From<LLVMDLLStorageClass> for DllStorageClass {
+    fn from(value: LLVMDLLStorageClass) -> Self {
+        match value {
+            LLVMDLLStorageClass::LLVMDefaultStorageClass => Self::Default,
+            LLVMDLLStorageClass::LLVMDLLImportStorageClass => Self::Import,
+            LLVMDLLStorageClass::LLVMDLLExportStorageClass => Self::Export,
+        }
+    }
+}
+
+/// Whether the address of a global value is significant.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UnnamedAddr {
+    /// The address is significant.
+    None,
+    /// The address is not significant within the module.
+    Local,
+    /// The address is not significant anywhere, so equal globals can be merged.
+    Global,
+}
+
+impl From<UnnamedAddr> for LLVMUnnamedAddr {
+    fn from(value: UnnamedAddr) -> Self {
+        match value {
+            UnnamedAddr::None => Self::LLVMNoUnnamedAddr,
+            UnnamedAddr::Local => Self::LLVMLocalUnnamedAddr,
+            UnnamedAddr::Global => Self::LLVMGlobalUnnamedAddr,
+        }
+    }
+}
+
+impl From<LLVMUnnamedAddr> for UnnamedAddr {
+    fn from(value: LLVMUnnamedAddr) -> Self {
+        match value {
+            LLVMUnnamedAddr::LLVMNoUnnamedAddr => Self::None,
+            LLVMUnnamedAddr::LLVMLocalUnnamedAddr => Self::Local,
+            LLVMUnnamedAddr::LLVMGlobalUnnamedAddr => Self::Global,
+        }
+    }
+}
+
+/// Thread-local storage model of a global variable.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ThreadLocalMode {
+    NotThreadLocal,
+    GeneralDynamic,
+    LocalDynamic,
+    InitialExec,
+    LocalExec,
+}
+
+impl From<ThreadLocalMode> for LLVMThreadLocalMode {
+    fn from(value: ThreadLocalMode) -> Self {
+        match value {
+            ThreadLocalMode::NotThreadLocal => Self::LLVMNotThreadLocal,
+            ThreadLocalMode::GeneralDynamic => Self::LLVMGeneralDynamicTLSModel,
+            ThreadLocalMode::LocalDynamic => Self::LLVMLocalDynamicTLSModel,
+            ThreadLocalMode::InitialExec => Self::LLVMInitialExecTLSModel,
+            ThreadLocalMode::LocalExec => Self::LLVMLocalExecTLSModel,
+        }
+    }
+}
+
+impl From<LLVMThreadLocalMode> for ThreadLocalMode {
+    fn from(value: LLVMThreadLocalMode) -> Self {
+        match value {
+            LLVMThreadLocalMode::LLVMNotThreadLocal => Self::NotThreadLocal,
+            LLVMThreadLocalMode::LLVMGeneralDynamicTLSModel => Self::GeneralDynamic,
+            LLVMThreadLocalMode::LLVMLocalDynamicTLSModel => Self::LocalDynamic,
+            LLVMThreadLocalMode::LLVMInitialExecTLSModel => Self::InitialExec,
+            LLVMThreadLocalMode::LLVMLocalExecTLSModel => Self::LocalExec,
+        }
+    }
+}
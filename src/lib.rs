@@ -1,48 +1,296 @@
 pub mod basic_block;
 pub mod builder;
 pub mod context;
+pub mod error;
+pub mod global_variable;
+pub mod memory_buffer;
 pub mod module;
+pub mod ssa;
 pub mod target;
 pub mod ty;
 mod util;
 pub mod value;
 
-use std::ffi::CString;
+use std::sync::Once;
 
+use error::Error;
 pub use llvm_sys;
-use llvm_sys::{
-    target::{
-        LLVM_InitializeAllAsmParsers, LLVM_InitializeAllAsmPrinters, LLVM_InitializeAllTargetInfos,
-        LLVM_InitializeAllTargetMCs, LLVM_InitializeAllTargets,
-    },
-    target_machine::LLVMGetDefaultTargetTriple,
+#[cfg(feature = "target-aarch64")]
+use llvm_sys::target::{
+    LLVMInitializeAArch64AsmParser, LLVMInitializeAArch64AsmPrinter, LLVMInitializeAArch64Target,
+    LLVMInitializeAArch64TargetInfo, LLVMInitializeAArch64TargetMC,
 };
-use util::cstring_to_string;
+#[cfg(feature = "target-amdgpu")]
+use llvm_sys::target::{
+    LLVMInitializeAMDGPUAsmParser, LLVMInitializeAMDGPUAsmPrinter, LLVMInitializeAMDGPUTarget,
+    LLVMInitializeAMDGPUTargetInfo, LLVMInitializeAMDGPUTargetMC,
+};
+#[cfg(feature = "target-arm")]
+use llvm_sys::target::{
+    LLVMInitializeARMAsmParser, LLVMInitializeARMAsmPrinter, LLVMInitializeARMTarget,
+    LLVMInitializeARMTargetInfo, LLVMInitializeARMTargetMC,
+};
+#[cfg(feature = "target-nvptx")]
+use llvm_sys::target::{
+    LLVMInitializeNVPTXAsmPrinter, LLVMInitializeNVPTXTarget, LLVMInitializeNVPTXTargetInfo,
+    LLVMInitializeNVPTXTargetMC,
+};
+#[cfg(feature = "target-riscv")]
+use llvm_sys::target::{
+    LLVMInitializeRISCVAsmParser, LLVMInitializeRISCVAsmPrinter, LLVMInitializeRISCVTarget,
+    LLVMInitializeRISCVTargetInfo, LLVMInitializeRISCVTargetMC,
+};
+#[cfg(feature = "target-webassembly")]
+use llvm_sys::target::{
+    LLVMInitializeWebAssemblyAsmParser, LLVMInitializeWebAssemblyAsmPrinter,
+    LLVMInitializeWebAssemblyTarget, LLVMInitializeWebAssemblyTargetInfo,
+    LLVMInitializeWebAssemblyTargetMC,
+};
+#[cfg(feature = "target-x86")]
+use llvm_sys::target::{
+    LLVMInitializeX86AsmParser, LLVMInitializeX86AsmPrinter, LLVMInitializeX86Target,
+    LLVMInitializeX86TargetInfo, LLVMInitializeX86TargetMC,
+};
+#[cfg(feature = "all-targets")]
+use llvm_sys::target::{
+    LLVM_InitializeAllAsmParsers, LLVM_InitializeAllAsmPrinters, LLVM_InitializeAllTargetInfos,
+    LLVM_InitializeAllTargetMCs, LLVM_InitializeAllTargets, LLVM_InitializeNativeAsmParser,
+    LLVM_InitializeNativeAsmPrinter, LLVM_InitializeNativeTarget,
+};
+use llvm_sys::target_machine::{
+    LLVMGetDefaultTargetTriple, LLVMGetHostCPUFeatures, LLVMGetHostCPUName,
+    LLVMNormalizeTargetTriple,
+};
+use util::{string_to_cstring, LlvmString};
 
 #[cfg(test)]
 mod test;
 
 pub fn get_default_target_triple() -> String {
-    let target_triple = unsafe { CString::from_raw(LLVMGetDefaultTargetTriple()) };
-    cstring_to_string(target_triple)
+    unsafe { LlvmString::new(LLVMGetDefaultTargetTriple()) }
+        .expect("default target triple is null")
+        .to_string()
+}
+
+pub fn normalize_target_triple<S: ToString>(triple: S) -> String {
+    let triple = string_to_cstring(triple.to_string());
+
+    unsafe { LlvmString::new(LLVMNormalizeTargetTriple(triple.as_ptr())) }
+        .expect("normalized target triple is null")
+        .to_string()
 }
 
+pub fn host_cpu_name() -> String {
+    unsafe { LlvmString::new(LLVMGetHostCPUName()) }
+        .expect("host cpu name is null")
+        .to_string()
+}
+
+pub fn host_cpu_features() -> String {
+    unsafe { LlvmString::new(LLVMGetHostCPUFeatures()) }
+        .expect("host cpu features are null")
+        .to_string()
+}
+
+#[cfg(feature = "all-targets")]
 pub fn initialize_all_target_infos() {
     unsafe { LLVM_InitializeAllTargetInfos() };
 }
 
+#[cfg(feature = "all-targets")]
 pub fn initialize_all_targets() {
     unsafe { LLVM_InitializeAllTargets() };
 }
 
+#[cfg(feature = "all-targets")]
 pub fn initialize_all_target_mcs() {
     unsafe { LLVM_InitializeAllTargetMCs() };
 }
 
+#[cfg(feature = "all-targets")]
 pub fn initialize_all_asm_parsers() {
     unsafe { LLVM_InitializeAllAsmParsers() };
 }
 
+#[cfg(feature = "all-targets")]
 pub fn initialize_all_asm_printers() {
     unsafe { LLVM_InitializeAllAsmPrinters() };
 }
+
+/// Initialize the target of the host machine, with its asm parser and asm printer.
+pub fn initialize_native_target() -> Result<(), Error> {
+    #[cfg(feature = "all-targets")]
+    let failed = unsafe {
+        LLVM_InitializeNativeTarget() != 0
+            || LLVM_InitializeNativeAsmParser() != 0
+            || LLVM_InitializeNativeAsmPrinter() != 0
+    };
+
+    // Without `all-targets` only the per-target features are linked in, so pick the one
+    // matching the host architecture.
+    #[cfg(not(feature = "all-targets"))]
+    let failed = !initialize_native_backend();
+
+    if failed {
+        return Err(Error::TargetLookup(String::from(
+            "native target is not available",
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(not(feature = "all-targets"))]
+#[allow(unreachable_code)]
+fn initialize_native_backend() -> bool {
+    #[cfg(all(
+        feature = "target-x86",
+        any(target_arch = "x86", target_arch = "x86_64")
+    ))]
+    {
+        initialize_x86();
+        return true;
+    }
+
+    #[cfg(all(feature = "target-aarch64", target_arch = "aarch64"))]
+    {
+        initialize_aarch64();
+        return true;
+    }
+
+    #[cfg(all(feature = "target-arm", target_arch = "arm"))]
+    {
+        initialize_arm();
+        return true;
+    }
+
+    #[cfg(all(
+        feature = "target-riscv",
+        any(target_arch = "riscv32", target_arch = "riscv64")
+    ))]
+    {
+        initialize_riscv();
+        return true;
+    }
+
+    #[cfg(all(
+        feature = "target-webassembly",
+        any(target_arch = "wasm32", target_arch = "wasm64")
+    ))]
+    {
+        initialize_webassembly();
+        return true;
+    }
+
+    false
+}
+
+#[cfg(feature = "target-x86")]
+pub fn initialize_x86() {
+    unsafe {
+        LLVMInitializeX86TargetInfo();
+        LLVMInitializeX86Target();
+        LLVMInitializeX86TargetMC();
+        LLVMInitializeX86AsmParser();
+        LLVMInitializeX86AsmPrinter();
+    }
+}
+
+#[cfg(feature = "target-aarch64")]
+pub fn initialize_aarch64() {
+    unsafe {
+        LLVMInitializeAArch64TargetInfo();
+        LLVMInitializeAArch64Target();
+        LLVMInitializeAArch64TargetMC();
+        LLVMInitializeAArch64AsmParser();
+        LLVMInitializeAArch64AsmPrinter();
+    }
+}
+
+#[cfg(feature = "target-arm")]
+pub fn initialize_arm() {
+    unsafe {
+        LLVMInitializeARMTargetInfo();
+        LLVMInitializeARMTarget();
+        LLVMInitializeARMTargetMC();
+        LLVMInitializeARMAsmParser();
+        LLVMInitializeARMAsmPrinter();
+    }
+}
+
+#[cfg(feature = "target-riscv")]
+pub fn initialize_riscv() {
+    unsafe {
+        LLVMInitializeRISCVTargetInfo();
+        LLVMInitializeRISCVTarget();
+        LLVMInitializeRISCVTargetMC();
+        LLVMInitializeRISCVAsmParser();
+        LLVMInitializeRISCVAsmPrinter();
+    }
+}
+
+#[cfg(feature = "target-webassembly")]
+pub fn initialize_webassembly() {
+    unsafe {
+        LLVMInitializeWebAssemblyTargetInfo();
+        LLVMInitializeWebAssemblyTarget();
+        LLVMInitializeWebAssemblyTargetMC();
+        LLVMInitializeWebAssemblyAsmParser();
+        LLVMInitializeWebAssemblyAsmPrinter();
+    }
+}
+
+#[cfg(feature = "target-nvptx")]
+pub fn initialize_nvptx() {
+    unsafe {
+        LLVMInitializeNVPTXTargetInfo();
+        LLVMInitializeNVPTXTarget();
+        LLVMInitializeNVPTXTargetMC();
+        LLVMInitializeNVPTXAsmPrinter();
+    }
+}
+
+#[cfg(feature = "target-amdgpu")]
+pub fn initialize_amdgpu() {
+    unsafe {
+        LLVMInitializeAMDGPUTargetInfo();
+        LLVMInitializeAMDGPUTarget();
+        LLVMInitializeAMDGPUTargetMC();
+        LLVMInitializeAMDGPUAsmParser();
+        LLVMInitializeAMDGPUAsmPrinter();
+    }
+}
+
+/// Initialize the native target and every target enabled by a cargo feature exactly once.
+pub(crate) fn initialize_targets_once() {
+    static INITIALIZE: Once = Once::new();
+
+    INITIALIZE.call_once(|| {
+        // Cross compiling without the host backend is still possible, so this may fail.
+        let _ = initialize_native_target();
+
+        #[cfg(feature = "all-targets")]
+        {
+            initialize_all_target_infos();
+            initialize_all_targets();
+            initialize_all_target_mcs();
+            initialize_all_asm_parsers();
+            initialize_all_asm_printers();
+        }
+
+        #[cfg(feature = "target-x86")]
+        initialize_x86();
+        #[cfg(feature = "target-aarch64")]
+        initialize_aarch64();
+        #[cfg(feature = "target-arm")]
+        initialize_arm();
+        #[cfg(feature = "target-riscv")]
+        initialize_riscv();
+        #[cfg(feature = "target-webassembly")]
+        initialize_webassembly();
+        #[cfg(feature = "target-nvptx")]
+        initialize_nvptx();
+        #[cfg(feature = "target-amdgpu")]
+        initialize_amdgpu();
+    });
+}
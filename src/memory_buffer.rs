@@ -0,0 +1,77 @@
+use std::{mem::ManuallyDrop, slice};
+
+use llvm_sys::{
+    core::{
+        LLVMCreateMemoryBufferWithMemoryRangeCopy, LLVMDisposeMemoryBuffer, LLVMGetBufferSize,
+        LLVMGetBufferStart,
+    },
+    prelude::LLVMMemoryBufferRef,
+};
+
+use crate::{error::Error, util::string_to_cstring};
+
+#[derive(Debug)]
+pub struct MemoryBuffer(LLVMMemoryBufferRef);
+
+impl MemoryBuffer {
+    /// Create a new [`MemoryBuffer`] from a [`LLVMMemoryBufferRef`].
+    pub(crate) fn new(pointer: LLVMMemoryBufferRef) -> Result<Self, Error> {
+        if pointer.is_null() {
+            return Err(Error::NullHandle("memory buffer"));
+        }
+
+        Ok(Self(pointer))
+    }
+
+    #[inline]
+    /// Get inner [`LLVMMemoryBufferRef`].
+    pub(crate) fn get(&self) -> LLVMMemoryBufferRef {
+        self.0
+    }
+
+    /// Give up ownership of the inner [`LLVMMemoryBufferRef`] to LLVM.
+    pub(crate) fn into_raw(self) -> LLVMMemoryBufferRef {
+        ManuallyDrop::new(self).get()
+    }
+
+    /// Create a [`MemoryBuffer`] holding a copy of `bytes`, diagnostics refer to it as `name`.
+    pub fn from_bytes<S: ToString>(bytes: &[u8], name: S) -> Result<Self, Error> {
+        let name = string_to_cstring(name.to_string());
+
+        unsafe {
+            Self::new(LLVMCreateMemoryBufferWithMemoryRangeCopy(
+                bytes.as_ptr() as *const _,
+                bytes.len(),
+                name.as_ptr(),
+            ))
+        }
+    }
+
+    /// Create a [`MemoryBuffer`] holding the contents of a file.
+    pub fn from_file<S: ToString>(path: S) -> Result<Self, Error> {
+        let path = path.to_string();
+        let bytes = std::fs::read(&path)?;
+
+        Self::from_bytes(&bytes, path)
+    }
+
+    /// Get the contents of this [`MemoryBuffer`].
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe {
+            let start = LLVMGetBufferStart(self.get()) as *const u8;
+            let size = LLVMGetBufferSize(self.get());
+
+            if start.is_null() {
+                return &[];
+            }
+
+            slice::from_raw_parts(start, size)
+        }
+    }
+}
+
+impl Drop for MemoryBuffer {
+    fn drop(&mut self) {
+        unsafe { LLVMDisposeMemoryBuffer(self.get()) }
+    }
+}
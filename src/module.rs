@@ -1,24 +1,40 @@
-use std::ptr::null_mut;
+use std::{fmt, marker::PhantomData, ptr::null_mut};
 
 use llvm_sys::{
-    bit_writer::LLVMWriteBitcodeToFile,
-    core::{LLVMAddFunction, LLVMDisposeModule, LLVMSetTarget},
+    analysis::{LLVMVerifierFailureAction, LLVMVerifyModule},
+    bit_writer::{LLVMWriteBitcodeToFile, LLVMWriteBitcodeToMemoryBuffer},
+    core::{
+        LLVMAddFunction, LLVMAddGlobalInAddressSpace, LLVMDisposeModule, LLVMGetDataLayoutStr,
+        LLVMGetNamedGlobal, LLVMPrintModuleToFile, LLVMPrintModuleToString, LLVMSetDataLayout,
+        LLVMSetTarget,
+    },
     prelude::LLVMModuleRef,
-    target_machine::{LLVMCodeGenFileType, LLVMCodeGenOptLevel, LLVMCodeModel, LLVMRelocMode},
 };
 
 use crate::{
-    get_default_target_triple, initialize_all_asm_parsers, initialize_all_asm_printers, initialize_all_target_infos, initialize_all_target_mcs, initialize_all_targets, target::{Target, TargetMachine}, ty::Type, util::string_to_cstring, value::Value
+    context::Context,
+    error::{Error, ParseDiagnostic},
+    get_default_target_triple,
+    global_variable::GlobalVariable,
+    initialize_targets_once,
+    memory_buffer::MemoryBuffer,
+    target::{check_data_layout, CodegenOptions, Target, TargetMachine},
+    ty::{AddressSpace, FunctionType, Type},
+    util::{borrowed_to_string, string_to_cstring, LlvmString},
+    value::{FunctionValue, Value},
 };
 
-#[derive(Debug, Clone)]
-pub struct Module(LLVMModuleRef);
+#[derive(Debug)]
+pub struct Module<'ctx>(LLVMModuleRef, PhantomData<&'ctx Context>);
 
-impl Module {
+impl<'ctx> Module<'ctx> {
     /// Creates a new [`Module`] from a [`LLVMModuleRef`].
-    pub(crate) fn new(pointer: LLVMModuleRef) -> Self {
-        assert_ne!(pointer, null_mut(), "module pointer is null");
-        Self(pointer)
+    pub(crate) fn new(pointer: LLVMModuleRef) -> Result<Self, Error> {
+        if pointer.is_null() {
+            return Err(Error::NullHandle("module"));
+        }
+
+        Ok(Self(pointer, PhantomData))
     }
 
     /// Get the inner [`LLVMModuleRef`].
@@ -33,51 +49,175 @@ impl Module {
         unsafe { LLVMSetTarget(self.get(), target.as_ptr()) }
     }
 
+    /// Set module data layout, malformed layouts are rejected with [`Error::Parse`].
+    pub fn set_data_layout<S: ToString>(&self, data_layout: S) -> Result<(), Error> {
+        let data_layout = data_layout.to_string();
+
+        // LLVM aborts the process on a malformed layout, so it is checked here first.
+        check_data_layout(&data_layout)
+            .map_err(|message| Error::Parse(ParseDiagnostic::new(message)))?;
+
+        let data_layout = string_to_cstring(data_layout);
+        unsafe { LLVMSetDataLayout(self.get(), data_layout.as_ptr()) };
+
+        Ok(())
+    }
+
+    /// Get module data layout.
+    pub fn get_data_layout(&self) -> String {
+        unsafe { borrowed_to_string(LLVMGetDataLayoutStr(self.get())) }.unwrap_or_default()
+    }
+
     /// Add a new function to this [`Module`].
-    pub fn add_function<S: ToString>(&self, name: S, func_ty: &Type) -> Value {
+    pub fn add_function<S: ToString>(
+        &self,
+        name: S,
+        func_ty: &FunctionType<'ctx>,
+    ) -> FunctionValue<'ctx> {
         let name = string_to_cstring(name.to_string());
 
-        unsafe { Value::new(LLVMAddFunction(self.get(), name.as_ptr(), func_ty.get())) }
+        let func = unsafe { Value::new(LLVMAddFunction(self.get(), name.as_ptr(), func_ty.get())) };
+
+        FunctionValue::new(func)
     }
 
-    /// Write bitcode to file.
-    pub fn write_bitcode_to_file<S: ToString>(&self, path: S) -> bool {
+    /// Add a new [`GlobalVariable`] of type `ty` to this [`Module`].
+    pub fn add_global<S: ToString>(
+        &self,
+        ty: &Type<'ctx>,
+        name: S,
+        address_space: AddressSpace,
+    ) -> GlobalVariable<'ctx> {
+        let name = string_to_cstring(name.to_string());
+
+        let global = unsafe {
+            Value::new(LLVMAddGlobalInAddressSpace(
+                self.get(),
+                ty.get(),
+                name.as_ptr(),
+                address_space as u32,
+            ))
+        };
+
+        GlobalVariable::new(global)
+    }
+
+    /// Get a [`GlobalVariable`] of this [`Module`] by name.
+    pub fn get_global<S: ToString>(&self, name: S) -> Option<GlobalVariable<'ctx>> {
+        let name = string_to_cstring(name.to_string());
+        let global = unsafe { LLVMGetNamedGlobal(self.get(), name.as_ptr()) };
+
+        if global.is_null() {
+            return None;
+        }
+
+        Some(GlobalVariable::new(Value::new(global)))
+    }
+
+    /// Verify this [`Module`], returning the verifier diagnostics on failure.
+    pub fn verify(&self) -> Result<(), Error> {
+        let mut message = null_mut();
+
+        let failed = unsafe {
+            LLVMVerifyModule(
+                self.get(),
+                LLVMVerifierFailureAction::LLVMReturnStatusAction,
+                &mut message,
+            ) != 0
+        };
+        let message = unsafe { LlvmString::new(message) };
+
+        if !failed {
+            return Ok(());
+        }
+
+        Err(Error::Verification(
+            message
+                .map(|message| message.to_string())
+                .unwrap_or_else(|| String::from("module failed verification")),
+        ))
+    }
+
+    /// Print the textual IR of this [`Module`] to a file.
+    pub fn print_to_file<S: ToString>(&self, path: S) -> Result<(), Error> {
         let path = string_to_cstring(path.to_string());
+        let mut error = null_mut();
+
+        let failed = unsafe { LLVMPrintModuleToFile(self.get(), path.as_ptr(), &mut error) != 0 };
+        let error_message = unsafe { LlvmString::new(error) };
+
+        if !failed {
+            return Ok(());
+        }
+
+        Err(Error::Emission(
+            error_message
+                .map(|message| message.to_string())
+                .unwrap_or_else(|| String::from("failed to print module")),
+        ))
+    }
+
+    /// Write bitcode to file.
+    pub fn write_bitcode_to_file<S: ToString>(&self, path: S) -> Result<(), Error> {
+        let path = path.to_string();
+        let c_path = string_to_cstring(path.clone());
 
-        unsafe { LLVMWriteBitcodeToFile(self.get(), path.as_ptr()) == 1 }
+        if unsafe { LLVMWriteBitcodeToFile(self.get(), c_path.as_ptr()) } != 0 {
+            return Err(Error::Emission(format!(
+                "failed to write bitcode to `{path}`"
+            )));
+        }
+
+        Ok(())
     }
 
-    /// Write object file.
+    /// Write bitcode to memory.
+    pub fn write_bitcode_to_memory(&self) -> Result<Vec<u8>, Error> {
+        let buffer = unsafe { MemoryBuffer::new(LLVMWriteBitcodeToMemoryBuffer(self.get()))? };
+
+        Ok(buffer.as_bytes().to_vec())
+    }
+
+    /// Write object file, or the output kind selected in [`CodegenOptions`].
     pub fn write_object_file(
-        self,
+        &self,
         target_triple: Option<String>,
         file: String,
-    ) -> Result<(), String> {
-        initialize_all_target_infos();
-        initialize_all_targets();
-        initialize_all_target_mcs();
-        initialize_all_asm_parsers();
-        initialize_all_asm_printers();
+        options: &CodegenOptions,
+    ) -> Result<(), Error> {
+        let Some(file_type) = options.output_kind.file_type() else {
+            return self.write_bitcode_to_file(file);
+        };
+
+        initialize_targets_once();
 
-        let target_triple = target_triple.unwrap_or_else(|| get_default_target_triple());
+        let target_triple = target_triple.unwrap_or_else(get_default_target_triple);
 
         let target = Target::get_target_from_triple(target_triple.as_str())?;
 
         let target_machine = TargetMachine::create(
             target,
             target_triple.as_str(),
-            "",
-            "",
-            LLVMCodeGenOptLevel::LLVMCodeGenLevelDefault,
-            LLVMRelocMode::LLVMRelocPIC,
-            LLVMCodeModel::LLVMCodeModelDefault,
-        );
-
-        target_machine.emit_to_file(self, file, LLVMCodeGenFileType::LLVMObjectFile)
+            options.resolved_cpu(),
+            options.resolved_features(),
+            options.opt_level,
+            options.reloc_mode,
+            options.code_model,
+        )?;
+
+        target_machine.emit_to_file(self, file, file_type)
     }
+}
+
+impl fmt::Display for Module<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ir = unsafe { LlvmString::new(LLVMPrintModuleToString(self.get())) };
+        ir.map_or(Ok(()), |ir| ir.fmt(f))
+    }
+}
 
-    /// Dispose of this [`Module`] and free its memory.
-    pub fn dispose(self) {
+impl Drop for Module<'_> {
+    fn drop(&mut self) {
         unsafe { LLVMDisposeModule(self.get()) }
     }
 }
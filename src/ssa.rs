@@ -0,0 +1,226 @@
+//! SSA construction following Braun et al., "Simple and Efficient Construction of Static
+//! Single Assignment Form" (CC 2013).
+//!
+//! Variables are defined per [`BasicBlock`] with [`SsaBuilder::write_variable`] and read back
+//! with [`SsaBuilder::read_variable`], `Phi` instructions are only inserted where control flow
+//! actually merges different definitions. A block must be sealed with
+//! [`SsaBuilder::seal_block`] once all of its predecessors have been terminated.
+
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use crate::{
+    basic_block::BasicBlock,
+    builder::Builder,
+    context::Context,
+    error::Error,
+    ty::Type,
+    value::{PhiValue, Value},
+};
+
+#[derive(Debug)]
+pub struct SsaBuilder<'ctx, V> {
+    builder: Builder<'ctx>,
+    types: HashMap<V, Type<'ctx>>,
+    definitions: HashMap<V, HashMap<BasicBlock<'ctx>, Value<'ctx>>>,
+    incomplete_phis: HashMap<BasicBlock<'ctx>, Vec<(V, PhiValue<'ctx>)>>,
+    sealed_blocks: HashSet<BasicBlock<'ctx>>,
+    filling_phis: HashSet<Value<'ctx>>,
+}
+
+impl<'ctx, V: Clone + Eq + Hash> SsaBuilder<'ctx, V> {
+    /// Create a new [`SsaBuilder`] in the given [`Context`].
+    pub fn new(context: &'ctx Context) -> Result<Self, Error> {
+        Ok(Self {
+            builder: context.create_builder()?,
+            types: HashMap::new(),
+            definitions: HashMap::new(),
+            incomplete_phis: HashMap::new(),
+            sealed_blocks: HashSet::new(),
+            filling_phis: HashSet::new(),
+        })
+    }
+
+    /// Declare a variable and its [`Type`], which must happen before it is read.
+    pub fn declare_variable(&mut self, variable: V, ty: Type<'ctx>) {
+        self.types.insert(variable, ty);
+    }
+
+    /// Define the [`Value`] of a variable at the end of a [`BasicBlock`].
+    pub fn write_variable(&mut self, variable: V, block: BasicBlock<'ctx>, value: Value<'ctx>) {
+        self.types
+            .entry(variable.clone())
+            .or_insert(value.get_type());
+        self.definitions
+            .entry(variable)
+            .or_default()
+            .insert(block, value);
+    }
+
+    /// Get the [`Value`] of a variable at the end of a [`BasicBlock`], inserting `Phi`
+    /// instructions as needed.
+    ///
+    /// Reading a variable that has never been declared or written panics.
+    pub fn read_variable(&mut self, variable: &V, block: BasicBlock<'ctx>) -> Value<'ctx> {
+        let value = self
+            .definitions
+            .get(variable)
+            .and_then(|definitions| definitions.get(&block));
+
+        match value {
+            Some(value) => *value,
+            None => self.read_variable_recursive(variable, block),
+        }
+    }
+
+    /// Mark a [`BasicBlock`] as having all of its predecessors terminated, completing the
+    /// `Phi` instructions that were created while it was open.
+    pub fn seal_block(&mut self, block: BasicBlock<'ctx>) {
+        for (variable, phi) in self.incomplete_phis.remove(&block).unwrap_or_default() {
+            self.add_phi_operands(&variable, phi, block);
+        }
+
+        self.sealed_blocks.insert(block);
+    }
+
+    /// Has the [`BasicBlock`] been sealed.
+    pub fn is_sealed(&self, block: &BasicBlock<'ctx>) -> bool {
+        self.sealed_blocks.contains(block)
+    }
+
+    fn read_variable_recursive(&mut self, variable: &V, block: BasicBlock<'ctx>) -> Value<'ctx> {
+        let ty = *self
+            .types
+            .get(variable)
+            .expect("variable is read before it is declared");
+
+        let value = if !self.is_sealed(&block) {
+            // Not every predecessor is known yet, complete the Phi once the block is sealed.
+            let phi = self.build_phi_at_start(block, &ty);
+            self.incomplete_phis
+                .entry(block)
+                .or_default()
+                .push((variable.clone(), phi));
+            phi.as_value()
+        } else {
+            match block.get_predecessors().as_slice() {
+                [] => ty.get_undef(),
+                [predecessor] => self.read_variable(variable, *predecessor),
+                _ => {
+                    // Break cycles by defining the variable before reading the predecessors.
+                    let phi = self.build_phi_at_start(block, &ty);
+                    self.write_variable(variable.clone(), block, phi.as_value());
+                    self.add_phi_operands(variable, phi, block)
+                }
+            }
+        };
+
+        self.write_variable(variable.clone(), block, value);
+        value
+    }
+
+    fn add_phi_operands(
+        &mut self,
+        variable: &V,
+        phi: PhiValue<'ctx>,
+        block: BasicBlock<'ctx>,
+    ) -> Value<'ctx> {
+        self.filling_phis.insert(phi.as_value());
+
+        for predecessor in block.get_predecessors() {
+            let value = self.read_variable(variable, predecessor);
+            phi.add_incoming(&[(value, predecessor)]);
+        }
+
+        self.filling_phis.remove(&phi.as_value());
+
+        let mut replaced = HashMap::new();
+        let value = self.try_remove_trivial_phi(phi, &mut replaced);
+
+        // Removing the users of `phi` may also have removed the value that replaced it.
+        resolve_replaced(&replaced, value)
+    }
+
+    /// Replace a `Phi` that only merges a single [`Value`] (and itself) with that [`Value`],
+    /// recording every removed `Phi` and its replacement in `replaced`.
+    fn try_remove_trivial_phi(
+        &mut self,
+        phi: PhiValue<'ctx>,
+        replaced: &mut HashMap<Value<'ctx>, Value<'ctx>>,
+    ) -> Value<'ctx> {
+        let mut same = None;
+
+        for (value, _) in phi.get_incomings() {
+            if Some(value) == same || value == phi.as_value() {
+                continue;
+            }
+
+            if same.is_some() {
+                return phi.as_value();
+            }
+
+            same = Some(value);
+        }
+
+        let same = same.unwrap_or_else(|| phi.as_value().get_type().get_undef());
+
+        let users = phi
+            .as_value()
+            .get_users()
+            .into_iter()
+            .filter(|user| *user != phi.as_value())
+            .collect::<Vec<_>>();
+
+        phi.as_value().replace_all_uses_with(&same);
+        self.replace_definitions(phi.as_value(), same);
+        replaced.insert(phi.as_value(), same);
+        // Every use was replaced above and `phi` is only reached through `replaced` from now on.
+        unsafe { phi.as_instruction().erase_from_parent() };
+
+        for user in users {
+            // Phis still receiving operands are checked once they are complete.
+            if replaced.contains_key(&user) || self.filling_phis.contains(&user) {
+                continue;
+            }
+
+            if let Some(user) = PhiValue::from_value(user) {
+                self.try_remove_trivial_phi(user, replaced);
+            }
+        }
+
+        same
+    }
+
+    fn replace_definitions(&mut self, old: Value<'ctx>, new: Value<'ctx>) {
+        for definitions in self.definitions.values_mut() {
+            for value in definitions.values_mut() {
+                if *value == old {
+                    *value = new;
+                }
+            }
+        }
+    }
+
+    fn build_phi_at_start(&self, block: BasicBlock<'ctx>, ty: &Type<'ctx>) -> PhiValue<'ctx> {
+        match block.get_first_instruction() {
+            Some(instr) => self.builder.position_before(&instr),
+            None => self.builder.position_at_end(&block),
+        }
+
+        self.builder.build_phi(ty, "")
+    }
+}
+
+/// Follow `value` through the `Phi`s removed in favour of it, to the [`Value`] that is left.
+fn resolve_replaced<'ctx>(
+    replaced: &HashMap<Value<'ctx>, Value<'ctx>>,
+    mut value: Value<'ctx>,
+) -> Value<'ctx> {
+    while let Some(replacement) = replaced.get(&value) {
+        value = *replacement;
+    }
+
+    value
+}
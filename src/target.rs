@@ -1,21 +1,465 @@
-use std::{ffi::CString, ptr::null_mut};
+use std::{fmt, iter::successors, ptr::null_mut};
 
 use llvm_sys::{
-    target::LLVMTargetDataRef,
+    core::LLVMTypeIsSized,
+    target::{
+        LLVMABIAlignmentOfType, LLVMABISizeOfType, LLVMByteOrder, LLVMByteOrdering,
+        LLVMCopyStringRepOfTargetData, LLVMCreateTargetData, LLVMDisposeTargetData,
+        LLVMElementAtOffset, LLVMIntPtrTypeForASInContext, LLVMOffsetOfElement,
+        LLVMPointerSizeForAS, LLVMPreferredAlignmentOfType, LLVMSizeOfTypeInBits,
+        LLVMStoreSizeOfType, LLVMTargetDataRef,
+    },
     target_machine::{
-        LLVMCodeGenFileType, LLVMCodeGenOptLevel, LLVMCodeModel, LLVMCreateTargetDataLayout, LLVMCreateTargetMachine, LLVMGetTargetFromName, LLVMGetTargetFromTriple, LLVMRelocMode, LLVMTargetMachineEmitToFile, LLVMTargetMachineRef, LLVMTargetRef
+        LLVMCodeGenFileType, LLVMCodeGenOptLevel, LLVMCodeModel, LLVMCreateTargetDataLayout,
+        LLVMCreateTargetMachine, LLVMDisposeTargetMachine, LLVMGetFirstTarget, LLVMGetNextTarget,
+        LLVMGetTargetDescription, LLVMGetTargetFromName, LLVMGetTargetFromTriple,
+        LLVMGetTargetMachineCPU, LLVMGetTargetMachineFeatureString, LLVMGetTargetMachineTarget,
+        LLVMGetTargetMachineTriple, LLVMGetTargetName, LLVMRelocMode, LLVMTargetHasAsmBackend,
+        LLVMTargetHasJIT, LLVMTargetHasTargetMachine, LLVMTargetMachineEmitToFile,
+        LLVMTargetMachineEmitToMemoryBuffer, LLVMTargetMachineRef, LLVMTargetRef,
     },
 };
 
-use crate::{module::Module, util::{cstring_to_string, string_to_cstring}};
+use crate::{
+    context::Context,
+    error::{Error, ParseDiagnostic},
+    get_default_target_triple, host_cpu_features, host_cpu_name, initialize_targets_once,
+    memory_buffer::MemoryBuffer,
+    module::Module,
+    normalize_target_triple,
+    ty::{AddressSpace, IntType, StructType, Type},
+    util::{borrowed_to_string, string_to_cstring, LlvmString},
+};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug)]
 pub struct TargetData(LLVMTargetDataRef);
 
 impl TargetData {
     pub fn get(&self) -> LLVMTargetDataRef {
         self.0
     }
+
+    /// Parse a data layout string, malformed layouts are rejected with [`Error::Parse`].
+    pub fn create<S: ToString>(layout: S) -> Result<Self, Error> {
+        let layout = layout.to_string();
+
+        // LLVM aborts the process on a malformed layout, so it is checked here first.
+        check_data_layout(&layout)
+            .map_err(|message| Error::Parse(ParseDiagnostic::new(message)))?;
+
+        let layout = string_to_cstring(layout);
+        let pointer = unsafe { LLVMCreateTargetData(layout.as_ptr()) };
+
+        if pointer.is_null() {
+            return Err(Error::NullHandle("target data"));
+        }
+
+        Ok(TargetData(pointer))
+    }
+
+    pub fn get_byte_order(&self) -> ByteOrder {
+        unsafe { LLVMByteOrder(self.get()).into() }
+    }
+
+    pub fn get_pointer_size(&self, address_space: AddressSpace) -> u32 {
+        unsafe { LLVMPointerSizeForAS(self.get(), address_space as u32) }
+    }
+
+    pub fn get_intptr_type<'ctx>(
+        &self,
+        context: &'ctx Context,
+        address_space: AddressSpace,
+    ) -> IntType<'ctx> {
+        unsafe {
+            IntType::new(Type::new(LLVMIntPtrTypeForASInContext(
+                context.get(),
+                self.get(),
+                address_space as u32,
+            )))
+        }
+    }
+
+    pub fn get_bit_size(&self, ty: &Type) -> u64 {
+        unsafe { LLVMSizeOfTypeInBits(self.get(), ty.get()) }
+    }
+
+    pub fn get_store_size(&self, ty: &Type) -> u64 {
+        unsafe { LLVMStoreSizeOfType(self.get(), ty.get()) }
+    }
+
+    pub fn get_abi_size(&self, ty: &Type) -> u64 {
+        unsafe { LLVMABISizeOfType(self.get(), ty.get()) }
+    }
+
+    pub fn get_abi_alignment(&self, ty: &Type) -> u32 {
+        unsafe { LLVMABIAlignmentOfType(self.get(), ty.get()) }
+    }
+
+    pub fn get_preferred_alignment(&self, ty: &Type) -> u32 {
+        unsafe { LLVMPreferredAlignmentOfType(self.get(), ty.get()) }
+    }
+
+    /// Get the byte offset of element `index`, `None` if the struct has no layout or no
+    /// such element.
+    pub fn get_element_offset(&self, struct_ty: &StructType, index: u32) -> Option<u64> {
+        if !has_layout(struct_ty) || index as usize >= struct_ty.count_elements() {
+            return None;
+        }
+
+        unsafe { Some(LLVMOffsetOfElement(self.get(), struct_ty.get(), index)) }
+    }
+
+    /// Get the index of the element containing byte `offset`, `None` if the struct has no
+    /// layout or `offset` lies past its end.
+    pub fn get_element_at_offset(&self, struct_ty: &StructType, offset: u64) -> Option<u32> {
+        if !has_layout(struct_ty)
+            || struct_ty.count_elements() == 0
+            || offset >= self.get_abi_size(&struct_ty.as_type())
+        {
+            return None;
+        }
+
+        unsafe { Some(LLVMElementAtOffset(self.get(), struct_ty.get(), offset)) }
+    }
+}
+
+impl fmt::Display for TargetData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let layout = unsafe { LlvmString::new(LLVMCopyStringRepOfTargetData(self.get())) };
+        layout.map_or(Ok(()), |layout| layout.fmt(f))
+    }
+}
+
+impl Drop for TargetData {
+    fn drop(&mut self) {
+        unsafe { LLVMDisposeTargetData(self.get()) }
+    }
+}
+
+/// Can LLVM compute a struct layout for `struct_ty`, i.e. is it sized and not opaque.
+fn has_layout(struct_ty: &StructType) -> bool {
+    !struct_ty.is_opaque() && unsafe { LLVMTypeIsSized(struct_ty.get()) == 1 }
+}
+
+/// Check `layout` against the grammar of `DataLayout::parseSpecifier`, reporting the same
+/// messages LLVM would pass to `report_fatal_error`.
+///
+/// This follows the parser of LLVM 18, it has to be updated alongside `llvm-sys` whenever the
+/// data layout grammar changes.
+pub(crate) fn check_data_layout(layout: &str) -> Result<(), String> {
+    const UNSIGNED: u64 = u32::MAX as u64;
+    const ADDRESS_SPACE: u64 = (1 << 24) - 1;
+    const ALIGNMENT: u64 = u16::MAX as u64;
+
+    let mut specs = layout;
+
+    while !specs.is_empty() {
+        let (spec, next) = split_layout(specs, '-')?;
+        specs = next;
+
+        let (token, mut rest) = split_layout(spec, ':')?;
+
+        if token == "ni" {
+            loop {
+                let (space, next) = split_layout(rest, ':')?;
+                rest = next;
+
+                if layout_int(space, UNSIGNED)? == 0 {
+                    return Err(String::from("Address space 0 can never be non-integral"));
+                }
+
+                if rest.is_empty() {
+                    break;
+                }
+            }
+
+            continue;
+        }
+
+        let mut chars = token.chars();
+        let specifier = chars.next().unwrap_or_default();
+        let token = chars.as_str();
+
+        match specifier {
+            's' | 'E' | 'e' => {}
+            'p' => {
+                let space = if token.is_empty() {
+                    0
+                } else {
+                    layout_int(token, UNSIGNED)?
+                };
+
+                if space > ADDRESS_SPACE {
+                    return Err(String::from(
+                        "Invalid address space, must be a 24-bit integer",
+                    ));
+                }
+
+                if rest.is_empty() {
+                    return Err(String::from(
+                        "Missing size specification for pointer in datalayout string",
+                    ));
+                }
+
+                let (size, next) = split_layout(rest, ':')?;
+                rest = next;
+                let size = layout_bytes(size, UNSIGNED)?;
+
+                if size == 0 {
+                    return Err(String::from("Invalid pointer size of 0 bytes"));
+                }
+
+                if rest.is_empty() {
+                    return Err(String::from(
+                        "Missing alignment specification for pointer in datalayout string",
+                    ));
+                }
+
+                let (abi_align, next) = split_layout(rest, ':')?;
+                rest = next;
+                let abi_align = layout_bytes(abi_align, UNSIGNED)?;
+
+                if !abi_align.is_power_of_two() {
+                    return Err(String::from("Pointer ABI alignment must be a power of 2"));
+                }
+
+                let mut pref_align = abi_align;
+                let mut index_size = size;
+
+                if !rest.is_empty() {
+                    let (pref, next) = split_layout(rest, ':')?;
+                    rest = next;
+                    pref_align = layout_bytes(pref, UNSIGNED)?;
+
+                    if !pref_align.is_power_of_two() {
+                        return Err(String::from(
+                            "Pointer preferred alignment must be a power of 2",
+                        ));
+                    }
+
+                    if !rest.is_empty() {
+                        let (index, _) = split_layout(rest, ':')?;
+                        index_size = layout_bytes(index, UNSIGNED)?;
+
+                        if index_size == 0 {
+                            return Err(String::from("Invalid index size of 0 bytes"));
+                        }
+                    }
+                }
+
+                if pref_align < abi_align {
+                    return Err(String::from(
+                        "Preferred alignment cannot be less than the ABI alignment",
+                    ));
+                }
+
+                if index_size > size {
+                    return Err(String::from(
+                        "Index width cannot be larger than pointer width",
+                    ));
+                }
+            }
+            'i' | 'v' | 'f' | 'a' => {
+                let size = if token.is_empty() {
+                    0
+                } else {
+                    layout_int(token, UNSIGNED)?
+                };
+
+                if specifier == 'a' && size != 0 {
+                    return Err(String::from(
+                        "Sized aggregate specification in datalayout string",
+                    ));
+                }
+
+                if rest.is_empty() {
+                    return Err(String::from(
+                        "Missing alignment specification in datalayout string",
+                    ));
+                }
+
+                let (abi_align, next) = split_layout(rest, ':')?;
+                rest = next;
+                let abi_align = layout_bytes(abi_align, UNSIGNED)?;
+
+                if specifier != 'a' && abi_align == 0 {
+                    return Err(String::from(
+                        "ABI alignment specification must be >0 for non-aggregate types",
+                    ));
+                }
+
+                if abi_align > ALIGNMENT {
+                    return Err(String::from(
+                        "Invalid ABI alignment, must be a 16bit integer",
+                    ));
+                }
+
+                if abi_align != 0 && !abi_align.is_power_of_two() {
+                    return Err(String::from("Invalid ABI alignment, must be a power of 2"));
+                }
+
+                if specifier == 'i' && size == 8 && abi_align != 1 {
+                    return Err(String::from(
+                        "Invalid ABI alignment, i8 must be naturally aligned",
+                    ));
+                }
+
+                let mut pref_align = abi_align;
+
+                if !rest.is_empty() {
+                    let (pref, _) = split_layout(rest, ':')?;
+                    pref_align = layout_bytes(pref, UNSIGNED)?;
+                }
+
+                if pref_align > ALIGNMENT {
+                    return Err(String::from(
+                        "Invalid preferred alignment, must be a 16bit integer",
+                    ));
+                }
+
+                if pref_align != 0 && !pref_align.is_power_of_two() {
+                    return Err(String::from(
+                        "Invalid preferred alignment, must be a power of 2",
+                    ));
+                }
+
+                if size > ADDRESS_SPACE {
+                    return Err(String::from("Invalid bit width, must be a 24-bit integer"));
+                }
+
+                if pref_align.max(1) < abi_align.max(1) {
+                    return Err(String::from(
+                        "Preferred alignment cannot be less than the ABI alignment",
+                    ));
+                }
+            }
+            'n' => {
+                let mut width = token;
+
+                loop {
+                    if layout_int(width, UNSIGNED)? == 0 {
+                        return Err(String::from(
+                            "Zero width native integer type in datalayout string",
+                        ));
+                    }
+
+                    if rest.is_empty() {
+                        break;
+                    }
+
+                    (width, rest) = split_layout(rest, ':')?;
+                }
+            }
+            'S' | 'F' => {
+                let alignment = if specifier == 'F' {
+                    let mut chars = token.chars();
+
+                    if !matches!(chars.next(), Some('i' | 'n')) {
+                        return Err(String::from(
+                            "Unknown function pointer alignment type in datalayout string",
+                        ));
+                    }
+
+                    chars.as_str()
+                } else {
+                    token
+                };
+
+                let alignment = layout_bytes(alignment, u64::MAX)?;
+
+                if alignment != 0 && !alignment.is_power_of_two() {
+                    return Err(String::from("Alignment is neither 0 nor a power of 2"));
+                }
+            }
+            'P' | 'A' | 'G' => {
+                if layout_int(token, UNSIGNED)? > ADDRESS_SPACE {
+                    return Err(String::from(
+                        "Invalid address space, must be a 24-bit integer",
+                    ));
+                }
+            }
+            'm' => {
+                if !token.is_empty() {
+                    return Err(String::from(
+                        "Unexpected trailing characters after mangling specifier in datalayout string",
+                    ));
+                }
+
+                if rest.is_empty() {
+                    return Err(String::from(
+                        "Expected mangling specifier in datalayout string",
+                    ));
+                }
+
+                if rest.len() > 1 {
+                    return Err(String::from(
+                        "Unknown mangling specifier in datalayout string",
+                    ));
+                }
+
+                if !matches!(rest, "e" | "l" | "o" | "x" | "w" | "m" | "a") {
+                    return Err(String::from("Unknown mangling in datalayout string"));
+                }
+            }
+            _ => return Err(String::from("Unknown specifier in datalayout string")),
+        }
+    }
+
+    Ok(())
+}
+
+/// Split a data layout string at the first `separator`, like `StringRef::split`.
+fn split_layout(text: &str, separator: char) -> Result<(&str, &str), String> {
+    let (first, second) = text.split_once(separator).unwrap_or((text, ""));
+
+    if second.is_empty() && first != text {
+        return Err(String::from("Trailing separator in datalayout string"));
+    }
+
+    if !second.is_empty() && first.is_empty() {
+        return Err(String::from(
+            "Expected token before separator in datalayout string",
+        ));
+    }
+
+    Ok((first, second))
+}
+
+/// Parse a decimal number of a data layout string that must not exceed `max`.
+fn layout_int(text: &str, max: u64) -> Result<u64, String> {
+    text.bytes()
+        .all(|byte| byte.is_ascii_digit())
+        .then(|| text.parse::<u64>().ok())
+        .flatten()
+        .filter(|value| *value <= max)
+        .ok_or_else(|| String::from("not a number, or does not fit in an unsigned int"))
+}
+
+/// Parse a bit width of a data layout string into bytes.
+fn layout_bytes(text: &str, max: u64) -> Result<u64, String> {
+    let bits = layout_int(text, max)?;
+
+    if bits % 8 != 0 {
+        return Err(String::from("number of bits must be a byte width multiple"));
+    }
+
+    Ok(bits / 8)
+}
+
+/// The byte order of a target.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ByteOrder {
+    BigEndian,
+    LittleEndian,
+}
+
+impl From<LLVMByteOrdering> for ByteOrder {
+    fn from(value: LLVMByteOrdering) -> Self {
+        match value {
+            LLVMByteOrdering::LLVMBigEndian => Self::BigEndian,
+            LLVMByteOrdering::LLVMLittleEndian => Self::LittleEndian,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -26,29 +470,70 @@ impl Target {
         self.0
     }
 
-    pub fn get_target_from_name<S: ToString>(name: S) -> Self {
-        let name = string_to_cstring(name.to_string());
-        unsafe { Target(LLVMGetTargetFromName(name.as_ptr())) }
+    pub fn get_target_from_name<S: ToString>(name: S) -> Result<Self, Error> {
+        let name = name.to_string();
+        let c_name = string_to_cstring(name.clone());
+        let target = unsafe { LLVMGetTargetFromName(c_name.as_ptr()) };
+
+        if target.is_null() {
+            return Err(Error::TargetLookup(format!("unknown target `{name}`")));
+        }
+
+        Ok(Target(target))
     }
 
-    pub fn get_target_from_triple<S: ToString>(triple: S) -> Result<Self, String> {
+    pub fn get_target_from_triple<S: ToString>(triple: S) -> Result<Self, Error> {
         let triple = string_to_cstring(triple.to_string());
         let mut target: LLVMTargetRef = null_mut();
-        let error_message = null_mut();
+        let mut error_message = null_mut();
 
-        let found =
-            unsafe { LLVMGetTargetFromTriple(triple.as_ptr(), &mut target, error_message) == 0 };
+        let found = unsafe {
+            LLVMGetTargetFromTriple(triple.as_ptr(), &mut target, &mut error_message) == 0
+        };
+        let error_message = unsafe { LlvmString::new(error_message) };
 
         if found {
             return Ok(Target(target));
         }
 
-        let error_message = unsafe { CString::from_raw(*error_message) };
-        Err(cstring_to_string(error_message))
+        Err(Error::TargetLookup(
+            error_message
+                .map(|message| message.to_string())
+                .unwrap_or_else(|| String::from("unknown target triple")),
+        ))
+    }
+
+    pub fn all() -> impl Iterator<Item = Target> {
+        let first = unsafe { LLVMGetFirstTarget() };
+
+        successors((!first.is_null()).then_some(Target(first)), |target| {
+            let next = unsafe { LLVMGetNextTarget(target.get()) };
+            (!next.is_null()).then_some(Target(next))
+        })
+    }
+
+    pub fn get_name(&self) -> String {
+        unsafe { borrowed_to_string(LLVMGetTargetName(self.get())) }.unwrap_or_default()
+    }
+
+    pub fn get_description(&self) -> String {
+        unsafe { borrowed_to_string(LLVMGetTargetDescription(self.get())) }.unwrap_or_default()
+    }
+
+    pub fn has_jit(&self) -> bool {
+        unsafe { LLVMTargetHasJIT(self.get()) == 1 }
+    }
+
+    pub fn has_target_machine(&self) -> bool {
+        unsafe { LLVMTargetHasTargetMachine(self.get()) == 1 }
+    }
+
+    pub fn has_asm_backend(&self) -> bool {
+        unsafe { LLVMTargetHasAsmBackend(self.get()) == 1 }
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug)]
 pub struct TargetMachine(LLVMTargetMachineRef);
 
 impl TargetMachine {
@@ -61,25 +546,81 @@ impl TargetMachine {
         triple: S,
         cpu: C,
         features: F,
-        level: LLVMCodeGenOptLevel,
-        reloc: LLVMRelocMode,
-        code_model: LLVMCodeModel,
-    ) -> Self {
+        level: OptLevel,
+        reloc: RelocMode,
+        code_model: CodeModel,
+    ) -> Result<Self, Error> {
         let triple = string_to_cstring(triple.to_string());
         let cpu = string_to_cstring(cpu.to_string());
         let features = string_to_cstring(features.to_string());
 
-        unsafe {
-            TargetMachine(LLVMCreateTargetMachine(
+        let pointer = unsafe {
+            LLVMCreateTargetMachine(
                 target.get(),
                 triple.as_ptr(),
                 cpu.as_ptr(),
                 features.as_ptr(),
-                level,
-                reloc,
-                code_model,
-            ))
+                level.into(),
+                reloc.into(),
+                code_model.into(),
+            )
+        };
+
+        if pointer.is_null() {
+            return Err(Error::NullHandle("target machine"));
         }
+
+        Ok(TargetMachine(pointer))
+    }
+
+    /// Create a [`TargetMachine`] for the host triple.
+    ///
+    /// Without an explicit CPU in `options`, or with `"native"`, the host CPU and its features
+    /// are used, followed by any features from `options`. Any other CPU is used as given, with
+    /// only the features from `options`.
+    pub fn native(options: &CodegenOptions) -> Result<Self, Error> {
+        initialize_targets_once();
+
+        let triple = normalize_target_triple(get_default_target_triple());
+        let target = Target::get_target_from_triple(triple.as_str())?;
+
+        let options = if options.cpu.is_empty() {
+            options.clone().cpu("native")
+        } else {
+            options.clone()
+        };
+
+        TargetMachine::create(
+            target,
+            triple,
+            options.resolved_cpu(),
+            options.resolved_features(),
+            options.opt_level,
+            options.reloc_mode,
+            options.code_model,
+        )
+    }
+
+    pub fn get_target(&self) -> Target {
+        unsafe { Target(LLVMGetTargetMachineTarget(self.get())) }
+    }
+
+    pub fn get_triple(&self) -> String {
+        unsafe { LlvmString::new(LLVMGetTargetMachineTriple(self.get())) }
+            .map(|triple| triple.to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn get_cpu(&self) -> String {
+        unsafe { LlvmString::new(LLVMGetTargetMachineCPU(self.get())) }
+            .map(|cpu| cpu.to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn get_feature_string(&self) -> String {
+        unsafe { LlvmString::new(LLVMGetTargetMachineFeatureString(self.get())) }
+            .map(|features| features.to_string())
+            .unwrap_or_default()
     }
 
     pub fn create_data_layout(&self) -> TargetData {
@@ -88,11 +629,11 @@ impl TargetMachine {
 
     pub fn emit_to_file<S: ToString>(
         &self,
-        module: Module,
+        module: &Module,
         file: S,
-        file_type: LLVMCodeGenFileType,
-    ) -> Result<(), String> {
-        let file = CString::new(file.to_string()).expect("failed to convert String to CString");
+        file_type: FileType,
+    ) -> Result<(), Error> {
+        let file = string_to_cstring(file.to_string());
         let mut error = null_mut();
 
         let errored = unsafe {
@@ -100,20 +641,252 @@ impl TargetMachine {
                 self.get(),
                 module.get(),
                 file.as_ptr() as *mut _,
-                file_type,
+                file_type.into(),
                 &mut error,
             ) == 1
         };
 
+        let error_message = unsafe { LlvmString::new(error) };
+
         if !errored {
             return Ok(());
         }
 
-        let error_message = unsafe { CString::from_raw(error) };
-        let error_message = error_message
-            .to_str()
-            .expect("failed to convert CString to String");
+        Err(Error::Emission(
+            error_message
+                .map(|message| message.to_string())
+                .unwrap_or_else(|| String::from("failed to emit file")),
+        ))
+    }
+
+    pub fn emit_to_memory(&self, module: &Module, file_type: FileType) -> Result<Vec<u8>, Error> {
+        let mut error = null_mut();
+        let mut buffer = null_mut();
+
+        let errored = unsafe {
+            LLVMTargetMachineEmitToMemoryBuffer(
+                self.get(),
+                module.get(),
+                file_type.into(),
+                &mut error,
+                &mut buffer,
+            ) == 1
+        };
+
+        let error_message = unsafe { LlvmString::new(error) };
+
+        if !errored {
+            return Ok(MemoryBuffer::new(buffer)?.as_bytes().to_vec());
+        }
+
+        Err(Error::Emission(
+            error_message
+                .map(|message| message.to_string())
+                .unwrap_or_else(|| String::from("failed to emit to memory")),
+        ))
+    }
+}
+
+impl Drop for TargetMachine {
+    fn drop(&mut self) {
+        unsafe { LLVMDisposeTargetMachine(self.get()) }
+    }
+}
+
+/// Code generation optimization levels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OptLevel {
+    None,
+    Less,
+    Default,
+    Aggressive,
+}
+
+impl From<OptLevel> for LLVMCodeGenOptLevel {
+    fn from(value: OptLevel) -> Self {
+        match value {
+            OptLevel::None => Self::LLVMCodeGenLevelNone,
+            OptLevel::Less => Self::LLVMCodeGenLevelLess,
+            OptLevel::Default => Self::LLVMCodeGenLevelDefault,
+            OptLevel::Aggressive => Self::LLVMCodeGenLevelAggressive,
+        }
+    }
+}
+
+/// Relocation models.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RelocMode {
+    Default,
+    Static,
+    Pic,
+    DynamicNoPic,
+    Ropi,
+    Rwpi,
+    RopiRwpi,
+}
+
+impl From<RelocMode> for LLVMRelocMode {
+    fn from(value: RelocMode) -> Self {
+        match value {
+            RelocMode::Default => Self::LLVMRelocDefault,
+            RelocMode::Static => Self::LLVMRelocStatic,
+            RelocMode::Pic => Self::LLVMRelocPIC,
+            RelocMode::DynamicNoPic => Self::LLVMRelocDynamicNoPic,
+            RelocMode::Ropi => Self::LLVMRelocROPI,
+            RelocMode::Rwpi => Self::LLVMRelocRWPI,
+            RelocMode::RopiRwpi => Self::LLVMRelocROPI_RWPI,
+        }
+    }
+}
+
+/// Code models.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CodeModel {
+    Default,
+    JitDefault,
+    Tiny,
+    Small,
+    Kernel,
+    Medium,
+    Large,
+}
+
+impl From<CodeModel> for LLVMCodeModel {
+    fn from(value: CodeModel) -> Self {
+        match value {
+            CodeModel::Default => Self::LLVMCodeModelDefault,
+            CodeModel::JitDefault => Self::LLVMCodeModelJITDefault,
+            CodeModel::Tiny => Self::LLVMCodeModelTiny,
+            CodeModel::Small => Self::LLVMCodeModelSmall,
+            CodeModel::Kernel => Self::LLVMCodeModelKernel,
+            CodeModel::Medium => Self::LLVMCodeModelMedium,
+            CodeModel::Large => Self::LLVMCodeModelLarge,
+        }
+    }
+}
+
+/// The kind of file emitted by a [`TargetMachine`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileType {
+    Assembly,
+    Object,
+}
+
+impl From<FileType> for LLVMCodeGenFileType {
+    fn from(value: FileType) -> Self {
+        match value {
+            FileType::Assembly => Self::LLVMAssemblyFile,
+            FileType::Object => Self::LLVMObjectFile,
+        }
+    }
+}
+
+/// The kind of file produced by code generation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputKind {
+    Object,
+    Assembly,
+    Bitcode,
+}
+
+impl OutputKind {
+    /// Get the [`FileType`] emitted by a [`TargetMachine`], bitcode has none.
+    pub(crate) fn file_type(self) -> Option<FileType> {
+        match self {
+            OutputKind::Object => Some(FileType::Object),
+            OutputKind::Assembly => Some(FileType::Assembly),
+            OutputKind::Bitcode => None,
+        }
+    }
+}
+
+/// Options controlling how a [`Module`] is compiled.
+#[derive(Debug, Clone)]
+pub struct CodegenOptions {
+    pub(crate) cpu: String,
+    pub(crate) features: String,
+    pub(crate) opt_level: OptLevel,
+    pub(crate) reloc_mode: RelocMode,
+    pub(crate) code_model: CodeModel,
+    pub(crate) output_kind: OutputKind,
+}
+
+impl Default for CodegenOptions {
+    fn default() -> Self {
+        Self {
+            cpu: String::new(),
+            features: String::new(),
+            opt_level: OptLevel::Default,
+            reloc_mode: RelocMode::Pic,
+            code_model: CodeModel::Default,
+            output_kind: OutputKind::Object,
+        }
+    }
+}
+
+impl CodegenOptions {
+    /// Create [`CodegenOptions`] for a generic CPU producing PIC object files.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the target CPU, `"native"` selects the host CPU.
+    pub fn cpu<S: ToString>(mut self, cpu: S) -> Self {
+        self.cpu = cpu.to_string();
+        self
+    }
+
+    /// Set the target feature string, e.g. `"+avx2,-sse4a"`.
+    pub fn features<S: ToString>(mut self, features: S) -> Self {
+        self.features = features.to_string();
+        self
+    }
+
+    /// Set the optimization level.
+    pub fn opt_level(mut self, opt_level: OptLevel) -> Self {
+        self.opt_level = opt_level;
+        self
+    }
+
+    /// Set the relocation model.
+    pub fn reloc_mode(mut self, reloc_mode: RelocMode) -> Self {
+        self.reloc_mode = reloc_mode;
+        self
+    }
+
+    /// Set the code model.
+    pub fn code_model(mut self, code_model: CodeModel) -> Self {
+        self.code_model = code_model;
+        self
+    }
+
+    /// Set the kind of file to produce.
+    pub fn output_kind(mut self, output_kind: OutputKind) -> Self {
+        self.output_kind = output_kind;
+        self
+    }
+
+    /// Get the CPU name, with `"native"` resolved to the host CPU.
+    pub(crate) fn resolved_cpu(&self) -> String {
+        if self.cpu != "native" {
+            return self.cpu.clone();
+        }
+
+        host_cpu_name()
+    }
+
+    /// Get the feature string, prefixed with the host features for the `"native"` CPU.
+    pub(crate) fn resolved_features(&self) -> String {
+        if self.cpu != "native" {
+            return self.features.clone();
+        }
+
+        let mut features = host_cpu_features();
+        if !self.features.is_empty() {
+            features.push(',');
+            features.push_str(&self.features);
+        }
 
-        Err(error_message.to_string())
+        features
     }
 }
@@ -1,10 +1,38 @@
-use crate::{context::Context, value::VerifierFailureAction};
+use crate::{
+    builder::{IntPredicate, Opcode},
+    context::Context,
+    error::Error,
+    get_default_target_triple,
+    global_variable::{GlobalValue, Linkage, ThreadLocalMode, UnnamedAddr, Visibility},
+    host_cpu_features, host_cpu_name, initialize_native_target, normalize_target_triple,
+    ssa::SsaBuilder,
+    target::{
+        ByteOrder, CodegenOptions, FileType, OptLevel, OutputKind, RelocMode, Target, TargetData,
+        TargetMachine,
+    },
+    ty::{AddressSpace, ArrayType, FunctionType, IntType, StructType, TypeKind, VectorType},
+    value::{
+        FunctionValue, IndirectBrValue, InstructionValue, IntValue, PhiValue, PointerValue,
+        SwitchValue, VerifierFailureAction,
+    },
+};
+
+/// Initialize the host backend, which is missing when built without the feature for it.
+/// Tests that need it return early in that case.
+fn has_native_target() -> bool {
+    initialize_native_target().is_ok()
+}
+
+/// Get an Integer parameter of a function under test.
+fn int_param<'ctx>(func: &FunctionValue<'ctx>, index: usize) -> IntValue<'ctx> {
+    IntValue::from_value(func.get_param(index).unwrap()).unwrap()
+}
 
 #[test]
 fn test_create_context() {
-    let context = Context::create();
-    let builder = context.create_builder();
-    let module = context.create_module("test");
+    let context = Context::create().unwrap();
+    let builder = context.create_builder().unwrap();
+    let module = context.create_module("test").unwrap();
 
     let func_ret_ty = context.create_void_type();
     let func_param_tys = [];
@@ -17,9 +45,856 @@ fn test_create_context() {
 
     builder.build_return_void();
 
-    assert!(func.verify_function(VerifierFailureAction::PrintMessage));
+    assert!(func.verify(VerifierFailureAction::PrintMessage).is_ok());
+    assert!(FunctionValue::from_value(func.as_value()).is_some());
+    assert!(PointerValue::from_value(func.as_value()).is_some());
+    assert!(IntValue::from_value(func.as_value()).is_none());
+}
+
+#[test]
+fn test_invalid_target_triple() {
+    if !has_native_target() {
+        return;
+    }
+
+    assert!(Target::get_target_from_triple("not-a-real-triple").is_err());
+}
+
+#[test]
+fn test_verify_reports_diagnostics() {
+    let context = Context::create().unwrap();
+    let module = context.create_module("test").unwrap();
+
+    let func_ty = context.create_func_type(&context.create_void_type(), &[], false);
+    let func = module.add_function("missing_terminator", &func_ty);
+    context.append_basic_block(&func, "entry");
+
+    let Err(Error::Verification(message)) = module.verify() else {
+        panic!("expected module verification to fail");
+    };
+    assert!(message.contains("terminator"));
+
+    let Err(Error::Verification(message)) = func.verify(VerifierFailureAction::ReturnStatus) else {
+        panic!("expected function verification to fail");
+    };
+    assert!(message.contains("missing_terminator"));
+    assert!(message.contains("terminator"));
+}
+
+#[test]
+fn test_print_ir() {
+    let context = Context::create().unwrap();
+    let builder = context.create_builder().unwrap();
+    let module = context.create_module("test").unwrap();
+
+    let i32_ty = context.create_i32_type();
+    let func_ty = context.create_func_type(&i32_ty.into(), &[i32_ty.into()], false);
+    let func = module.add_function("identity", &func_ty);
+
+    let entry = context.append_basic_block(&func, "entry");
+    builder.position_at_end(&entry);
+    builder.build_return(&func.get_param(0).unwrap());
+
+    assert_eq!(i32_ty.to_string(), "i32");
+    assert_eq!(func.get_param(0).unwrap().to_string(), "i32 %0");
+    assert!(func.get_param(1).is_none());
+    assert!(module.to_string().contains("define i32 @identity(i32 %0)"));
+}
+
+#[test]
+fn test_parse_ir() {
+    let context = Context::create().unwrap();
+
+    let module = context
+        .parse_ir("define i32 @answer() {\n  ret i32 42\n}\n")
+        .unwrap();
+    assert!(module.verify().is_ok());
+    assert!(module.to_string().contains("ret i32 42"));
+
+    let Err(Error::Parse(diagnostic)) = context.parse_ir("define i32 @broken() {\n  ret i32\n}\n")
+    else {
+        panic!("expected IR parsing to fail");
+    };
+    assert!(diagnostic.line.is_some());
+    assert!(diagnostic.column.is_some());
+}
+
+#[test]
+fn test_bitcode_round_trip() {
+    let context = Context::create().unwrap();
+    let module = context
+        .parse_ir("define void @empty() {\n  ret void\n}\n")
+        .unwrap();
+
+    let bitcode = module.write_bitcode_to_memory().unwrap();
+    let parsed = context.parse_bitcode(&bitcode).unwrap();
+
+    assert!(parsed.to_string().contains("define void @empty()"));
+}
+
+#[test]
+fn test_parse_invalid_bitcode() {
+    let context = Context::create().unwrap();
+    let garbage = b"definitely not bitcode";
+
+    let Err(Error::Parse(diagnostic)) = context.parse_bitcode(garbage) else {
+        panic!("expected bitcode parsing to fail");
+    };
+    assert!(!diagnostic.message.is_empty());
+    assert_ne!(diagnostic.message, "invalid bitcode");
+
+    let Err(Error::Parse(diagnostic)) = context.parse_bitcode_lazy(garbage) else {
+        panic!("expected lazy bitcode loading to fail");
+    };
+    assert!(!diagnostic.message.is_empty());
+}
+
+#[test]
+fn test_write_assembly_file() {
+    if !has_native_target() {
+        return;
+    }
+
+    let context = Context::create().unwrap();
+    let module = context
+        .parse_ir("define void @empty() {\n  ret void\n}\n")
+        .unwrap();
+
+    let path = std::env::temp_dir().join("llvm_wrap_test_write_assembly_file.s");
+    let options = CodegenOptions::new()
+        .opt_level(OptLevel::None)
+        .reloc_mode(RelocMode::Static)
+        .output_kind(OutputKind::Assembly);
+
+    module
+        .write_object_file(None, path.display().to_string(), &options)
+        .unwrap();
+
+    let assembly = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(assembly.contains("empty"));
+}
+
+#[test]
+fn test_native_target_machine() {
+    if !has_native_target() {
+        return;
+    }
+
+    assert!(!host_cpu_name().is_empty());
+    assert_eq!(
+        normalize_target_triple(get_default_target_triple()),
+        normalize_target_triple(normalize_target_triple(get_default_target_triple()))
+    );
+
+    let context = Context::create().unwrap();
+    let module = context
+        .parse_ir("define void @empty() {\n  ret void\n}\n")
+        .unwrap();
+
+    let target_machine = TargetMachine::native(&CodegenOptions::new()).unwrap();
+    assert_eq!(target_machine.get_cpu(), host_cpu_name());
+    assert_eq!(target_machine.get_feature_string(), host_cpu_features());
+
+    // `native` and `write_object_file` must agree on the features of the host CPU.
+    let extra = host_cpu_features()
+        .split(',')
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    let options = CodegenOptions::new().cpu("native").features(&extra);
+    let with_extra = TargetMachine::native(&options).unwrap();
+    assert_eq!(with_extra.get_cpu(), options.resolved_cpu());
+    assert_eq!(with_extra.get_feature_string(), options.resolved_features());
+    assert!(options
+        .resolved_features()
+        .starts_with(&host_cpu_features()));
+
+    let generic = TargetMachine::native(&CodegenOptions::new().cpu("generic")).unwrap();
+    assert_eq!(generic.get_cpu(), "generic");
+    assert_eq!(generic.get_feature_string(), "");
+
+    let object = target_machine
+        .emit_to_memory(&module, FileType::Object)
+        .unwrap();
+    assert!(!object.is_empty());
+}
+
+#[test]
+fn test_enumerate_targets() {
+    if !has_native_target() {
+        return;
+    }
+
+    let triple = get_default_target_triple();
+    let native = Target::get_target_from_triple(triple.as_str()).unwrap();
+
+    assert!(Target::all().any(|target| target.get_name() == native.get_name()));
+    assert!(native.has_target_machine());
+
+    let target_machine = TargetMachine::native(&CodegenOptions::new()).unwrap();
+    assert_eq!(target_machine.get_target().get_name(), native.get_name());
+    assert_eq!(target_machine.get_cpu(), host_cpu_name());
+}
+
+#[test]
+fn test_target_data_layout() {
+    let context = Context::create().unwrap();
+    let module = context.create_module("test").unwrap();
+    let target_data = TargetData::create("e-p:64:64-i64:64-n8:16:32:64-S128").unwrap();
+
+    module.set_data_layout(&target_data).unwrap();
+    assert_eq!(module.get_data_layout(), target_data.to_string());
+
+    let i8_ty = context.create_i8_type();
+    let i64_ty = context.create_i64_type();
+    let struct_ty = context.create_struct_type(&[i8_ty.into(), i64_ty.into()], false);
+
+    assert_eq!(target_data.get_byte_order(), ByteOrder::LittleEndian);
+    assert_eq!(target_data.get_pointer_size(AddressSpace::Generic), 8);
+    assert_eq!(target_data.get_store_size(&struct_ty.into()), 16);
+    assert_eq!(target_data.get_abi_alignment(&i64_ty.into()), 8);
+    assert_eq!(target_data.get_element_offset(&struct_ty, 1), Some(8));
+    assert_eq!(target_data.get_element_offset(&struct_ty, 2), None);
+    assert_eq!(target_data.get_element_at_offset(&struct_ty, 9), Some(1));
+    assert_eq!(target_data.get_element_at_offset(&struct_ty, 16), None);
+
+    let opaque_ty = context.create_named_struct_type("opaque");
+    assert_eq!(target_data.get_element_offset(&opaque_ty, 0), None);
+    assert_eq!(target_data.get_element_at_offset(&opaque_ty, 0), None);
+    assert_eq!(
+        target_data
+            .get_intptr_type(&context, AddressSpace::Generic)
+            .get_width(),
+        64
+    );
+}
+
+#[test]
+fn test_invalid_target_data_layout() {
+    let context = Context::create().unwrap();
+    let module = context.create_module("test").unwrap();
+
+    for layout in [
+        "x",
+        "e-",
+        "e--p:64:64",
+        "p:0:64",
+        "p:64:48",
+        "p:64:64:32",
+        "p:32:32:32:64",
+        "i8:16",
+        "i32:0",
+        "a64:64",
+        "n8:0",
+        "S24",
+        "Fq8",
+        "m:q",
+        "ni:0",
+        "i64:+64",
+    ] {
+        let Err(Error::Parse(diagnostic)) = TargetData::create(layout) else {
+            panic!("expected {layout:?} to be rejected");
+        };
+        assert!(!diagnostic.message.is_empty());
+        assert!(matches!(
+            module.set_data_layout(layout),
+            Err(Error::Parse(_))
+        ));
+    }
+    assert_eq!(module.get_data_layout(), "");
+
+    assert!(TargetData::create("").is_ok());
+    assert!(TargetData::create(
+        "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-i128:128-f80:128-n8:16:32:64-S128"
+    )
+    .is_ok());
+}
+
+#[test]
+fn test_arithmetic_flags() {
+    let context = Context::create().unwrap();
+    let builder = context.create_builder().unwrap();
+    let module = context.create_module("test").unwrap();
+
+    let i32_ty = context.create_i32_type();
+    let func_ty = context.create_func_type(&i32_ty.into(), &[i32_ty.into(), i32_ty.into()], false);
+    let func = module.add_function("flags", &func_ty);
+
+    let entry = context.append_basic_block(&func, "entry");
+    builder.position_at_end(&entry);
+
+    let left = int_param(&func, 0);
+    let right = int_param(&func, 1);
+
+    left.as_value().set_name("left");
+    assert_eq!(left.as_value().get_name(), "left");
+
+    let sum = builder.build_nsw_add(&left, &right, "sum");
+    assert_eq!(sum.as_value().get_name(), "sum");
+
+    let sum_instr = InstructionValue::from_value(sum.as_value()).unwrap();
+    assert_eq!(sum_instr.get_opcode(), Opcode::Add);
+    assert_eq!(sum_instr.has_nsw(), Some(true));
+    assert_eq!(sum_instr.has_nuw(), Some(false));
+    assert_eq!(sum_instr.is_exact(), None);
+    assert!(!sum_instr.set_exact(true));
+
+    let quotient = builder.build_udiv(&sum, &right, "quotient");
+    let quotient_instr = InstructionValue::from_value(quotient.as_value()).unwrap();
+    assert!(quotient_instr.set_exact(true));
+    assert_eq!(quotient_instr.is_exact(), Some(true));
+    assert_eq!(quotient_instr.has_nsw(), None);
+    assert!(!quotient_instr.set_disjoint(true));
+
+    let mixed = builder
+        .build_binop(Opcode::Xor, &quotient.into(), &left.into(), "")
+        .unwrap();
+    assert!(mixed.to_string().contains("xor"));
+    assert!(builder
+        .build_binop(Opcode::Br, &quotient.into(), &left.into(), "")
+        .is_none());
+    assert!(builder
+        .build_cast(Opcode::Alloca, &mixed, &i32_ty.into(), "")
+        .is_none());
+
+    builder.build_return(&mixed);
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_ssa_builder_loop() {
+    let context = Context::create().unwrap();
+    let builder = context.create_builder().unwrap();
+    let module = context.create_module("test").unwrap();
+    let mut ssa = SsaBuilder::new(&context).unwrap();
+
+    let i32_ty = context.create_i32_type();
+    let func_ty = context.create_func_type(&i32_ty.into(), &[i32_ty.into()], false);
+    let func = module.add_function("count", &func_ty);
+
+    let entry = context.append_basic_block(&func, "entry");
+    let header = context.append_basic_block(&func, "header");
+    let body = context.append_basic_block(&func, "body");
+    let exit = context.append_basic_block(&func, "exit");
+
+    builder.position_at_end(&entry);
+    ssa.write_variable("i", entry, i32_ty.const_int(0, false).into());
+    ssa.write_variable("unchanged", entry, func.get_param(0).unwrap());
+    builder.build_br(&header);
+    ssa.seal_block(entry);
+
+    builder.position_at_end(&header);
+    let i = IntValue::from_value(ssa.read_variable(&"i", header)).unwrap();
+    let limit = int_param(&func, 0);
+    let condition = builder.build_icmp(IntPredicate::Slt, &i, &limit, "condition");
+    builder.build_cond_br(&condition, &body, &exit);
+
+    builder.position_at_end(&body);
+    let one = i32_ty.const_int(1, false);
+    let i = IntValue::from_value(ssa.read_variable(&"i", body)).unwrap();
+    let next = builder.build_add(&i, &one, "next");
+    ssa.write_variable("i", body, next.into());
+    builder.build_br(&header);
+    ssa.seal_block(body);
+    ssa.seal_block(header);
+
+    builder.position_at_end(&exit);
+    ssa.seal_block(exit);
+    let unchanged = ssa.read_variable(&"unchanged", exit);
+    assert_eq!(Some(unchanged), func.get_param(0));
+
+    let result = ssa.read_variable(&"i", exit);
+    let phi = PhiValue::from_value(result).expect("loop counter should be a phi");
+    assert_eq!(phi.count_incoming(), 2);
+    builder.build_return(&result);
+
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_ssa_builder_diamond_in_loop() {
+    let context = Context::create().unwrap();
+    let builder = context.create_builder().unwrap();
+    let module = context.create_module("test").unwrap();
+    let mut ssa = SsaBuilder::new(&context).unwrap();
+
+    let i32_ty = context.create_i32_type();
+    let func_ty = context.create_func_type(&i32_ty.into(), &[i32_ty.into()], false);
+    let func = module.add_function("diamond", &func_ty);
+
+    let entry = context.append_basic_block(&func, "entry");
+    let header = context.append_basic_block(&func, "header");
+    let left = context.append_basic_block(&func, "left");
+    let right = context.append_basic_block(&func, "right");
+    let latch = context.append_basic_block(&func, "latch");
+
+    builder.position_at_end(&entry);
+    let zero = func.get_param(0).unwrap();
+    ssa.write_variable("x", entry, zero);
+    builder.build_br(&header);
+
+    builder.position_at_end(&header);
+    let condition = builder.build_icmp(
+        IntPredicate::Eq,
+        &int_param(&func, 0),
+        &i32_ty.const_int(0, false),
+        "condition",
+    );
+    builder.build_cond_br(&condition, &left, &right);
+
+    builder.position_at_end(&left);
+    builder.build_br(&latch);
+    builder.position_at_end(&right);
+    builder.build_br(&latch);
+    builder.position_at_end(&latch);
+    builder.build_br(&header);
+
+    for block in [entry, header, left, right, latch] {
+        ssa.seal_block(block);
+    }
+
+    // The Phi in `latch` merges the Phi in `header` twice, removing it makes the Phi in
+    // `header` trivial as well, so both collapse into the parameter.
+    assert_eq!(ssa.read_variable(&"x", latch), zero);
+    assert_eq!(ssa.read_variable(&"x", header), zero);
+    assert_eq!(
+        latch.get_first_instruction().unwrap().get_opcode(),
+        Opcode::Br
+    );
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_void_call_name() {
+    let context = Context::create().unwrap();
+    let builder = context.create_builder().unwrap();
+    let module = context.create_module("test").unwrap();
+
+    let func_ty = context.create_func_type(&context.create_void_type(), &[], false);
+    let callee = module.add_function("callee", &func_ty);
+    let func = module.add_function("caller", &func_ty);
+
+    let entry = context.append_basic_block(&func, "entry");
+    builder.position_at_end(&entry);
+
+    let call = builder.build_call(&func_ty, &callee, &[], "result");
+    assert_eq!(call.get_name(), "");
+    call.set_name("result");
+    assert_eq!(call.get_name(), "");
+
+    builder.build_return_void();
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_unpositioned_builder() {
+    let context = Context::create().unwrap();
+    let builder = context.create_builder().unwrap();
+    let module = context.create_module("test").unwrap();
+
+    let func_ty = context.create_func_type(&context.create_void_type(), &[], false);
+    let func = module.add_function("target", &func_ty);
+    let entry = context.append_basic_block(&func, "entry");
+
+    assert_eq!(builder.get_insert_block(), None);
+
+    builder.position_at_end(&entry);
+    assert_eq!(builder.get_insert_block(), Some(entry));
+}
+
+#[test]
+fn test_switch_and_indirect_branch() {
+    let context = Context::create().unwrap();
+    let builder = context.create_builder().unwrap();
+    let module = context.create_module("test").unwrap();
+
+    let i32_ty = context.create_i32_type();
+    let func_ty = context.create_func_type(&context.create_void_type(), &[i32_ty.into()], false);
+    let func = module.add_function("dispatch", &func_ty);
+
+    let entry = context.append_basic_block(&func, "entry");
+    let jump = context.append_basic_block(&func, "jump");
+    let target = context.append_basic_block(&func, "target");
+    let fallback = context.append_basic_block(&func, "fallback");
+
+    builder.position_at_end(&entry);
+    let switch = builder.build_switch(&int_param(&func, 0), &fallback, 1);
+    switch.add_case(&i32_ty.const_int(7, false), &jump);
+    assert_eq!(entry.get_block_terminator(), Some(switch.as_instruction()));
+    assert!(IndirectBrValue::from_value(switch.as_value()).is_none());
+
+    builder.position_at_end(&jump);
+    let indirect = builder.build_indirect_br(&target.get_address().unwrap(), 1);
+    indirect.add_destination(&target);
+
+    builder.position_at_end(&target);
+    builder.build_return_void();
+
+    builder.position_at_end(&fallback);
+    let unreachable = builder.build_unreachable();
+    assert!(SwitchValue::from_value(unreachable.as_value()).is_none());
+
+    assert!(module.verify().is_ok());
+    assert_eq!(target.get_predecessors(), vec![jump]);
+}
+
+#[test]
+fn test_struct_field_access() {
+    let context = Context::create().unwrap();
+    let builder = context.create_builder().unwrap();
+    let module = context.create_module("test").unwrap();
+
+    let i32_ty = context.create_i32_type();
+    let i64_ty = context.create_i64_type();
+    let pair_ty = context.create_struct_type(&[i32_ty.into(), i64_ty.into()], false);
+    let array_ty = context.create_array_type(&pair_ty.into(), 4);
+
+    let func_ty = context.create_func_type(&i64_ty.into(), &[], false);
+    let func = module.add_function("second", &func_ty);
+
+    let entry = context.append_basic_block(&func, "entry");
+    builder.position_at_end(&entry);
+
+    let array = builder.build_alloca(&array_ty.into(), "array");
+    let zero = i32_ty.const_int(0, false);
+    let two = i32_ty.const_int(2, false);
+    let element = builder.build_inbounds_gep(&array_ty.into(), &array, &[zero, two], "element");
+    let field = builder.build_struct_gep(&pair_ty, &element, 1, "field");
+    builder.build_store(&i64_ty.const_int(42, false).into(), &field);
+
+    let pair = builder.build_load(&pair_ty.into(), &element, "pair");
+    let updated = builder.build_insert_value(&pair, &i32_ty.const_int(1, false).into(), 0, "");
+    let second = builder.build_extract_value(&updated, 1, "second");
+    builder.build_return(&second);
+
+    assert!(module.verify().is_ok());
+    assert!(module.to_string().contains("getelementptr inbounds"));
+}
+
+#[test]
+fn test_select_and_casts() {
+    let context = Context::create().unwrap();
+    let builder = context.create_builder().unwrap();
+    let module = context.create_module("test").unwrap();
+
+    let i8_ty = context.create_i8_type();
+    let i32_ty = context.create_i32_type();
+    let i64_ty = context.create_i64_type();
+    let ptr_ty = context.create_ptr_type(AddressSpace::Generic);
+
+    let func_ty = context.create_func_type(&i64_ty.into(), &[i32_ty.into(), ptr_ty.into()], false);
+    let func = module.add_function("clamp", &func_ty);
+
+    let entry = context.append_basic_block(&func, "entry");
+    builder.position_at_end(&entry);
+
+    let value = func.get_param(0).unwrap();
+    let frozen = IntValue::from_value(builder.build_freeze(&value, "frozen")).unwrap();
+    let zero = i32_ty.const_int(0, false);
+    let negative = builder.build_icmp(IntPredicate::Slt, &frozen, &zero, "negative");
+    let clamped = builder.build_select(&negative, &zero.into(), &frozen.into(), "clamped");
+    let clamped = IntValue::from_value(clamped).unwrap();
+
+    let narrow = builder.build_trunc(&clamped, &i8_ty, "narrow");
+    let widened = builder.build_int_cast(&narrow, &i64_ty, false, "widened");
+    let extended = builder.build_sext(&clamped, &i64_ty, "extended");
+
+    let argument = PointerValue::from_value(func.get_param(1).unwrap()).unwrap();
+    let address = builder.build_ptrtoint(&argument, &i64_ty, "address");
+    let pointer = builder.build_inttoptr(&address, &ptr_ty, "pointer");
+    builder.build_store(&narrow.into(), &pointer);
+
+    let sum = builder.build_add(&widened, &extended, "sum");
+    builder.build_return(&sum.into());
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.to_string();
+    assert!(ir.contains("freeze i32"));
+    assert!(ir.contains("select i1"));
+    assert!(ir.contains("trunc i32"));
+    assert!(ir.contains("zext i8"));
+    assert!(ir.contains("inttoptr i64"));
+}
+
+#[test]
+fn test_global_variable() {
+    let context = Context::create().unwrap();
+    let module = context.create_module("test").unwrap();
+
+    let i32_ty = context.create_i32_type();
+
+    let counter = module.add_global(&i32_ty.into(), "counter", AddressSpace::Generic);
+    assert!(counter.get_initializer().is_none());
+
+    counter.set_initializer(&i32_ty.const_int(7, false).into());
+    counter.set_constant(true);
+
+    let global = counter.as_global_value();
+    global.set_linkage(Linkage::Internal);
+    global.set_visibility(Visibility::Default);
+    global.set_unnamed_addr(UnnamedAddr::Global);
+    global.set_alignment(4);
+    global.set_section(".rodata.counter");
+
+    assert!(counter.is_constant());
+    assert_eq!(global.get_linkage(), Linkage::Internal);
+    assert_eq!(global.get_unnamed_addr(), UnnamedAddr::Global);
+    assert_eq!(global.get_alignment(), 4);
+    assert_eq!(global.get_section().as_deref(), Some(".rodata.counter"));
+    assert_eq!(GlobalValue::from_value(counter.as_value()), Some(global));
+
+    let state = module.add_global(&i32_ty.into(), "state", AddressSpace::Generic);
+    state.set_thread_local_mode(ThreadLocalMode::LocalExec);
+    assert_eq!(state.get_thread_local_mode(), ThreadLocalMode::LocalExec);
+
+    assert!(module.verify().is_ok());
+    assert!(module.to_string().contains(
+        "@counter = internal unnamed_addr constant i32 7, section \".rodata.counter\", align 4"
+    ));
+
+    assert_eq!(module.get_global("counter"), Some(counter));
+    unsafe { state.delete() };
+    assert!(module.get_global("state").is_none());
+}
+
+#[test]
+fn test_constants() {
+    let context = Context::create().unwrap();
+    let module = context.create_module("test").unwrap();
+
+    let i8_ty = context.create_i8_type();
+    let i32_ty = context.create_i32_type();
+    let i64_ty = context.create_i64_type();
+    let i128_ty = context.create_i128_type();
+    let f64_ty = context.create_f64_type();
+
+    let big = i128_ty.const_int_arbitrary_precision(&[0, 1]);
+    assert_eq!(big.to_string(), "i128 18446744073709551616");
+    assert_eq!(
+        i128_ty.const_int_from_string("18446744073709551616", 10),
+        Some(big)
+    );
+    assert_eq!(
+        i32_ty
+            .const_int_from_string("-ff", 16)
+            .unwrap()
+            .get_sext_value(),
+        Some(-255)
+    );
+    assert!(i32_ty.const_int_from_string("12z", 10).is_none());
+    assert!(i32_ty.const_int_from_string("10", 3).is_none());
+    assert!(i8_ty.const_int_from_string(&"9".repeat(40), 10).is_none());
+    assert!(i8_ty.const_int_from_string("111111111", 2).is_none());
+    assert_eq!(
+        i8_ty
+            .const_int_from_string("11111111", 2)
+            .unwrap()
+            .get_sext_value(),
+        Some(-1)
+    );
+    assert!(big.get_zext_value().is_none());
+
+    assert_eq!(f64_ty.const_real(0.5).to_string(), "double 5.000000e-01");
+    assert_eq!(
+        f64_ty.const_real_from_string("0.5"),
+        Some(f64_ty.const_real(0.5))
+    );
+    assert!(f64_ty.const_real_from_string("half").is_none());
+    assert_eq!(
+        f64_ty.const_real_from_string("0x1.8p1"),
+        Some(f64_ty.const_real(3.0))
+    );
+    assert!(f64_ty.const_real_from_string("-inf").is_some());
+    assert!(f64_ty.const_real_from_string("nan(0x1)").is_some());
+    assert!(context
+        .create_f128_type()
+        .const_real_from_string("1e5000")
+        .is_some());
+
+    for invalid in [
+        "", "-", ".", "1.2.3", "e5", "1e+x", "0x", "0x1.8", "0x.p1", "nan()",
+    ] {
+        assert!(f64_ty.const_real_from_string(invalid).is_none());
+    }
+
+    assert!(i32_ty.as_type().const_null().unwrap().is_null());
+    assert!(context.create_void_type().const_null().is_none());
+    assert!(context.create_label_type().const_null().is_none());
+    assert!(context
+        .create_named_struct_type("opaque")
+        .as_type()
+        .const_null()
+        .is_none());
+    assert_eq!(i8_ty.const_all_ones().get_sext_value(), Some(-1));
+    assert_eq!(i32_ty.as_type().get_undef().to_string(), "i32 undef");
+    assert_eq!(i32_ty.as_type().get_poison().to_string(), "i32 poison");
+
+    let one = i32_ty.const_int(1, false);
+    let two = i32_ty.const_int(2, false);
+    assert_eq!(one.const_add(&two).unwrap().get_zext_value(), Some(3));
+    assert_eq!(
+        two.const_mul(&two)
+            .and_then(|four| four.const_neg())
+            .unwrap()
+            .get_sext_value(),
+        Some(-4)
+    );
+    assert!(one
+        .const_icmp(IntPredicate::Ult, &two)
+        .unwrap()
+        .as_value()
+        .is_constant());
+
+    let func_ty = context.create_func_type(&i32_ty.into(), &[i32_ty.into()], false);
+    let func = module.add_function("not_constant", &func_ty);
+    let param = int_param(&func, 0);
+    assert!(one.const_add(&param).is_none());
+    assert!(param.const_not().is_none());
+    assert!(param.as_value().const_bit_cast(&i32_ty.into()).is_none());
+
+    let table_ty = context.create_array_type(&i32_ty.into(), 3);
+    let table = module.add_global(&table_ty.into(), "table", AddressSpace::Generic);
+    let null = i32_ty.as_type().const_null().unwrap();
+    table.set_initializer(
+        &i32_ty
+            .as_type()
+            .const_array(&[one.into(), two.into(), null]),
+    );
+
+    let message = context.const_string(b"hi", true);
+    let message_ty = message.get_type();
+    let greeting = module.add_global(&message_ty, "greeting", AddressSpace::Generic);
+    greeting.set_initializer(&message);
+    greeting.set_constant(true);
+
+    let pair = context.const_struct(&[one.into(), i64_ty.as_type().const_null().unwrap()], false);
+    let pair_global = module.add_global(&pair.get_type(), "pair", AddressSpace::Generic);
+    pair_global.set_initializer(&pair);
+
+    let zero = i32_ty.const_int(0, false);
+    let second = table
+        .as_pointer_value()
+        .const_inbounds_gep(&table_ty.into(), &[zero, one])
+        .unwrap();
+    assert!(table
+        .as_pointer_value()
+        .const_gep(&table_ty.into(), &[zero, param])
+        .is_none());
+    let address = second.const_ptrtoint(&i64_ty).unwrap();
+    let pointer = module.add_global(&i64_ty.into(), "second", AddressSpace::Generic);
+    pointer.set_initializer(&address.into());
+
+    let splat = context.const_vector(&[one.into(); 4]);
+    assert!(IntValue::from_value(splat).is_some());
+    assert_eq!(splat.to_string(), "<4 x i32> <i32 1, i32 1, i32 1, i32 1>");
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.to_string();
+    assert!(ir.contains("@table = global [3 x i32] [i32 1, i32 2, i32 0]"));
+    assert!(ir.contains("@greeting = constant [3 x i8] c\"hi\\00\""));
+    assert!(ir.contains("@pair = global { i32, i64 } { i32 1, i64 0 }"));
+    assert!(ir.contains(
+        "ptrtoint (ptr getelementptr inbounds ([3 x i32], ptr @table, i32 0, i32 1) to i64)"
+    ));
+}
+
+#[test]
+fn test_type_wrappers() {
+    let context = Context::create().unwrap();
+
+    let i16_ty = context.create_i16_type();
+    let f32_ty = context.create_f32_type();
+    let ptr_ty = context.create_ptr_type(AddressSpace::Generic);
+    let array_ty = context.create_array_type(&i16_ty.into(), 8);
+    let struct_ty = context.create_struct_type(&[i16_ty.into(), f32_ty.into()], true);
+    let named_ty = context.create_named_struct_type("node");
+    let func_ty = context.create_func_type(&ptr_ty.into(), &[array_ty.into()], false);
+
+    assert_eq!(i16_ty.get_width(), 16);
+    assert_eq!(f32_ty.as_type().get_type_kind(), TypeKind::Float);
+    assert!(ptr_ty.is_opaque());
+    assert_eq!(array_ty.get_element_type(), i16_ty.as_type());
+    assert_eq!(array_ty.get_length(), 8);
+
+    assert_eq!(struct_ty.count_elements(), 2);
+    assert_eq!(struct_ty.get_element_ty(1), Some(f32_ty.as_type()));
+    assert_eq!(struct_ty.get_element_ty(2), None);
+    assert!(struct_ty.is_packed() && struct_ty.is_literal());
+    assert_eq!(struct_ty.get_name(), None);
+    assert_eq!(named_ty.get_name().as_deref(), Some("node"));
+    assert!(named_ty.is_opaque());
+
+    assert_eq!(IntType::from_type(i16_ty.into()), Some(i16_ty));
+    assert_eq!(IntType::from_type(f32_ty.into()), None);
+    assert_eq!(StructType::from_type(array_ty.into()), None);
+    assert_eq!(ArrayType::from_type(array_ty.into()), Some(array_ty));
+    assert_eq!(FunctionType::from_type(func_ty.into()), Some(func_ty));
+    assert_eq!(func_ty.to_string(), "ptr ([8 x i16])");
+}
+
+#[test]
+fn test_function_type_introspection() {
+    let context = Context::create().unwrap();
+    let module = context.create_module("test").unwrap();
+
+    let i32_ty = context.create_i32_type();
+    let ptr_ty = context.create_ptr_type(AddressSpace::Generic);
+    let printf_ty = context.create_func_type(&i32_ty.into(), &[ptr_ty.into()], true);
+    let func_ty = context.create_func_type(&context.create_void_type(), &[], false);
+
+    assert_eq!(printf_ty.get_return_type(), i32_ty.as_type());
+    assert_eq!(printf_ty.count_params(), 1);
+    assert_eq!(printf_ty.get_param_types(), vec![ptr_ty.as_type()]);
+    assert!(printf_ty.is_var_arg());
+
+    assert_eq!(func_ty.get_return_type().get_type_kind(), TypeKind::Void);
+    assert!(func_ty.get_param_types().is_empty());
+    assert!(!func_ty.is_var_arg());
+
+    let printf = module.add_function("printf", &printf_ty);
+    assert_eq!(printf.get_function_type(), printf_ty);
+    assert_eq!(printf.as_value().get_type(), ptr_ty.as_type());
+}
+
+#[test]
+fn test_vector_and_special_types() {
+    let context = Context::create().unwrap();
+
+    let i32_ty = context.create_i32_type();
+    let f64_ty = context.create_f64_type();
+    let vector_ty = context.create_vector_type(&i32_ty.into(), 4);
+    let scalable_ty = context.create_scalable_vector_type(&f64_ty.into(), 2);
+
+    assert_eq!(vector_ty.to_string(), "<4 x i32>");
+    assert_eq!(vector_ty.get_element_type(), i32_ty.as_type());
+    assert_eq!(vector_ty.get_size(), 4);
+    assert!(!vector_ty.is_scalable());
+
+    assert_eq!(scalable_ty.to_string(), "<vscale x 2 x double>");
+    assert_eq!(
+        scalable_ty.as_type().get_type_kind(),
+        TypeKind::ScalableVector
+    );
+    assert_eq!(scalable_ty.get_size(), 2);
+    assert!(scalable_ty.is_scalable());
+    assert_eq!(VectorType::from_type(scalable_ty.into()), Some(scalable_ty));
+    assert_eq!(VectorType::from_type(i32_ty.into()), None);
+
+    let splat = vector_ty.const_all_ones().unwrap();
+    assert_eq!(splat.as_value().get_type(), vector_ty.as_type());
+    assert!(scalable_ty.const_all_ones().is_none());
 
-    module.dispose();
-    builder.dispose();
-    context.dispose();
+    assert_eq!(context.create_label_type().get_type_kind(), TypeKind::Label);
+    assert_eq!(
+        context.create_metadata_type().get_type_kind(),
+        TypeKind::Metadata
+    );
+    assert_eq!(context.create_token_type().to_string(), "token");
+    assert_eq!(
+        context.create_x86_amx_type().get_type_kind(),
+        TypeKind::X86Amx
+    );
 }
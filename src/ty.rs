@@ -1,25 +1,61 @@
-use std::{ffi::CString, ptr::null_mut};
+use std::{fmt, marker::PhantomData, ptr::null_mut};
 
 use llvm_sys::{
     core::{
-        LLVMCountStructElementTypes, LLVMGetArrayLength2, LLVMGetElementType, LLVMGetIntTypeWidth,
-        LLVMGetStructElementTypes, LLVMGetStructName, LLVMGetTypeKind, LLVMIsLiteralStruct,
-        LLVMIsOpaqueStruct, LLVMIsPackedStruct, LLVMPointerTypeIsOpaque, LLVMStructGetTypeAtIndex,
+        LLVMConstAllOnes, LLVMConstArray2, LLVMConstInt, LLVMConstIntOfArbitraryPrecision,
+        LLVMConstIntOfStringAndSize, LLVMConstNamedStruct, LLVMConstNull, LLVMConstReal,
+        LLVMConstRealOfStringAndSize, LLVMCountParamTypes, LLVMCountStructElementTypes,
+        LLVMGetArrayLength2, LLVMGetElementType, LLVMGetIntTypeWidth, LLVMGetParamTypes,
+        LLVMGetPoison, LLVMGetReturnType, LLVMGetStructElementTypes, LLVMGetStructName,
+        LLVMGetTypeKind, LLVMGetUndef, LLVMGetVectorSize, LLVMIsFunctionVarArg,
+        LLVMIsLiteralStruct, LLVMIsOpaqueStruct, LLVMIsPackedStruct, LLVMPointerTypeIsOpaque,
+        LLVMPrintTypeToString, LLVMStructGetTypeAtIndex,
     },
-    prelude::LLVMTypeRef,
+    prelude::{LLVMTypeRef, LLVMValueRef},
     LLVMTypeKind,
 };
 
-use crate::util::cstring_to_string;
+use crate::{
+    context::Context,
+    util::{borrowed_to_string, LlvmString},
+    value::{FloatValue, IntValue, Value},
+};
 
-#[derive(Debug, Clone, Copy)]
-pub struct Type(LLVMTypeRef);
+/// Implement raw access, the conversion into [`Type`] and [`fmt::Display`] for a typed
+/// wrapper around [`Type`].
+macro_rules! type_wrapper {
+    ($wrapper:ident) => {
+        impl $wrapper<'_> {
+            #[inline]
+            #[allow(dead_code)]
+            /// Get inner [`LLVMTypeRef`].
+            pub(crate) fn get(&self) -> LLVMTypeRef {
+                self.as_type().get()
+            }
+        }
+
+        impl<'ctx> From<$wrapper<'ctx>> for Type<'ctx> {
+            fn from(ty: $wrapper<'ctx>) -> Self {
+                ty.as_type()
+            }
+        }
+
+        impl fmt::Display for $wrapper<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.as_type().fmt(f)
+            }
+        }
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Type<'ctx>(LLVMTypeRef, PhantomData<&'ctx Context>);
 
-impl Type {
+impl<'ctx> Type<'ctx> {
     /// Creates a [`Type`] from a [`LLVMTypeRef`].
     pub(crate) fn new(pointer: LLVMTypeRef) -> Self {
-        assert_ne!(pointer, null_mut(), "type pointer is null");
-        Self(pointer)
+        assert!(!pointer.is_null(), "type pointer is null");
+        Self(pointer, PhantomData)
     }
 
     #[inline]
@@ -29,75 +65,663 @@ impl Type {
     }
 
     /// Get [`TypeKind`] of this [`Type`].
-    pub fn get_type_kind(&self) -> LLVMTypeKind {
-        unsafe { LLVMGetTypeKind(self.get()).into() }
+    pub fn get_type_kind(&self) -> TypeKind {
+        unsafe { LLVMGetTypeKind(self.get()) }.into()
+    }
+
+    /// Create the null constant of this [`Type`]: zero, a null pointer, or
+    /// `zeroinitializer` for aggregates.
+    ///
+    /// Types without a null value, such as `void`, labels, functions, metadata and opaque
+    /// structs, return `None`.
+    pub fn const_null(&self) -> Option<Value<'ctx>> {
+        let has_null = match self.get_type_kind() {
+            TypeKind::Integer
+            | TypeKind::Pointer
+            | TypeKind::Array
+            | TypeKind::Vector
+            | TypeKind::ScalableVector
+            | TypeKind::Token
+            | TypeKind::TargetExt => true,
+            TypeKind::Struct => !StructType::new(*self).is_opaque(),
+            kind => kind.is_float(),
+        };
+
+        if !has_null {
+            return None;
+        }
+
+        unsafe { Some(Value::new(LLVMConstNull(self.get()))) }
+    }
+
+    /// Get the `undef` value of this [`Type`].
+    pub fn get_undef(&self) -> Value<'ctx> {
+        unsafe { Value::new(LLVMGetUndef(self.get())) }
+    }
+
+    /// Get the `poison` value of this [`Type`].
+    pub fn get_poison(&self) -> Value<'ctx> {
+        unsafe { Value::new(LLVMGetPoison(self.get())) }
+    }
+
+    /// Create a constant Array of elements of this [`Type`].
+    pub fn const_array(&self, values: &[Value<'ctx>]) -> Value<'ctx> {
+        let mut values = values
+            .iter()
+            .map(|value| value.get())
+            .collect::<Vec<LLVMValueRef>>();
+
+        unsafe {
+            Value::new(LLVMConstArray2(
+                self.get(),
+                values.as_mut_ptr(),
+                values.len() as u64,
+            ))
+        }
+    }
+}
+
+impl fmt::Display for Type<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ir = unsafe { LlvmString::new(LLVMPrintTypeToString(self.get())) };
+        ir.map_or(Ok(()), |ir| ir.fmt(f))
+    }
+}
+
+/// An Integer [`Type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntType<'ctx>(Type<'ctx>);
+
+impl<'ctx> IntType<'ctx> {
+    /// Create a new [`IntType`] from an Integer [`Type`].
+    pub(crate) fn new(ty: Type<'ctx>) -> Self {
+        Self(ty)
+    }
+
+    /// Get the [`IntType`] for a [`Type`], if it is an Integer [`Type`].
+    pub fn from_type(ty: Type<'ctx>) -> Option<Self> {
+        if ty.get_type_kind() != TypeKind::Integer {
+            return None;
+        }
+
+        Some(Self(ty))
+    }
+
+    /// Get the underlying [`Type`].
+    pub fn as_type(&self) -> Type<'ctx> {
+        self.0
+    }
+
+    /// Get the bit width.
+    pub fn get_width(&self) -> u32 {
+        unsafe { LLVMGetIntTypeWidth(self.get()) }
+    }
+
+    /// Create a constant of this [`IntType`].
+    pub fn const_int(&self, value: u64, sign_extend: bool) -> IntValue<'ctx> {
+        unsafe {
+            IntValue::new(Value::new(LLVMConstInt(
+                self.get(),
+                value,
+                sign_extend as i32,
+            )))
+        }
+    }
+
+    /// Create a constant of this [`IntType`] from 64-bit words, least significant first.
+    pub fn const_int_arbitrary_precision(&self, words: &[u64]) -> IntValue<'ctx> {
+        unsafe {
+            IntValue::new(Value::new(LLVMConstIntOfArbitraryPrecision(
+                self.get(),
+                words.len() as u32,
+                words.as_ptr(),
+            )))
+        }
+    }
+
+    /// Create a constant of this [`IntType`] by parsing `text` in `radix`.
+    ///
+    /// LLVM only supports radix 2, 8, 10, 16 and 36 and does not report malformed text,
+    /// so both are checked here and rejected with `None`. So is text with more digits than
+    /// `APInt` accepts for the width of this type, values that still overflow it wrap around.
+    pub fn const_int_from_string(&self, text: &str, radix: u8) -> Option<IntValue<'ctx>> {
+        if !matches!(radix, 2 | 8 | 10 | 16 | 36) {
+            return None;
+        }
+
+        let digits = text.strip_prefix(['-', '+']).unwrap_or(text);
+
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix as u32)) {
+            return None;
+        }
+
+        // The estimates `APInt::fromString` asserts on, with leading zeros counted as digits.
+        let extra_digits = digits.len() as u64 - 1;
+        let min_width = match radix {
+            2 => extra_digits + 1,
+            8 => extra_digits * 3,
+            16 => extra_digits * 4,
+            _ => extra_digits * 64 / 22,
+        };
+
+        if min_width > self.get_width() as u64 {
+            return None;
+        }
+
+        unsafe {
+            Some(IntValue::new(Value::new(LLVMConstIntOfStringAndSize(
+                self.get(),
+                text.as_ptr() as *const _,
+                text.len() as u32,
+                radix,
+            ))))
+        }
+    }
+
+    /// Create a constant of this [`IntType`] with every bit set.
+    pub fn const_all_ones(&self) -> IntValue<'ctx> {
+        unsafe { IntValue::new(Value::new(LLVMConstAllOnes(self.get()))) }
+    }
+}
+
+type_wrapper!(IntType);
+
+/// A Float [`Type`] of any precision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FloatType<'ctx>(Type<'ctx>);
+
+impl<'ctx> FloatType<'ctx> {
+    /// Create a new [`FloatType`] from a Float [`Type`].
+    pub(crate) fn new(ty: Type<'ctx>) -> Self {
+        Self(ty)
+    }
+
+    /// Get the [`FloatType`] for a [`Type`], if it is a Float [`Type`].
+    pub fn from_type(ty: Type<'ctx>) -> Option<Self> {
+        if !ty.get_type_kind().is_float() {
+            return None;
+        }
+
+        Some(Self(ty))
+    }
+
+    /// Get the underlying [`Type`].
+    pub fn as_type(&self) -> Type<'ctx> {
+        self.0
+    }
+
+    /// Create a constant of this [`FloatType`].
+    pub fn const_real(&self, value: f64) -> FloatValue<'ctx> {
+        unsafe { FloatValue::new(Value::new(LLVMConstReal(self.get(), value))) }
+    }
+
+    /// Create a constant of this [`FloatType`] by parsing a decimal (`1.5e3`), hexadecimal
+    /// (`0x1.8p3`), `inf` or `nan` literal, keeping the full precision and range of wider
+    /// types such as `fp128`.
+    ///
+    /// LLVM asserts on text it cannot parse, so it is checked here and rejected with `None`.
+    pub fn const_real_from_string(&self, text: &str) -> Option<FloatValue<'ctx>> {
+        if !is_float_literal(text) {
+            return None;
+        }
+
+        unsafe {
+            Some(FloatValue::new(Value::new(LLVMConstRealOfStringAndSize(
+                self.get(),
+                text.as_ptr() as *const _,
+                text.len() as u32,
+            ))))
+        }
+    }
+}
+
+type_wrapper!(FloatType);
+
+/// Does `text` follow the grammar of `APFloat::convertFromString`.
+fn is_float_literal(text: &str) -> bool {
+    if is_float_special(text) {
+        return true;
+    }
+
+    let body = text.strip_prefix(['-', '+']).unwrap_or(text);
+
+    if let Some(hex) = body.strip_prefix("0x").or_else(|| body.strip_prefix("0X")) {
+        let Some((significand, exponent)) = hex.split_once(['p', 'P']) else {
+            return false;
+        };
+
+        return is_float_significand(significand, 16) && is_float_exponent(exponent, false);
+    }
+
+    let (significand, exponent) = body.split_once(['e', 'E']).unwrap_or((body, ""));
+
+    is_float_significand(significand, 10) && is_float_exponent(exponent, true)
+}
+
+/// Is `text` an infinity or a NaN with an optional payload, e.g. `-inf` or `snan(0x7)`.
+fn is_float_special(text: &str) -> bool {
+    if matches!(
+        text,
+        "inf" | "INFINITY" | "+Inf" | "-inf" | "-INFINITY" | "-Inf"
+    ) {
+        return true;
+    }
+
+    let text = text.strip_prefix('-').unwrap_or(text);
+    let text = text.strip_prefix(['s', 'S']).unwrap_or(text);
+
+    let Some(payload) = text
+        .strip_prefix("nan")
+        .or_else(|| text.strip_prefix("NaN"))
+    else {
+        return false;
+    };
+
+    if payload.is_empty() {
+        return true;
+    }
+
+    let payload = match payload.strip_prefix('(') {
+        Some(payload) => match payload.strip_suffix(')') {
+            Some(payload) if !payload.is_empty() => payload,
+            _ => return false,
+        },
+        None => payload,
+    };
+
+    let (digits, radix) = match payload
+        .strip_prefix("0x")
+        .or_else(|| payload.strip_prefix("0X"))
+    {
+        Some(digits) => (digits, 16),
+        None if payload.starts_with('0') => (payload, 8),
+        None => (payload, 10),
+    };
+
+    !digits.is_empty() && digits.chars().all(|c| c.is_digit(radix))
+}
+
+/// Is `text` a run of digits in `radix` with at most one `.` and at least one digit.
+fn is_float_significand(text: &str, radix: u32) -> bool {
+    let (integer, fraction) = text.split_once('.').unwrap_or((text, ""));
+    let digits = || integer.chars().chain(fraction.chars());
+
+    digits().next().is_some() && digits().all(|c| c.is_digit(radix))
+}
+
+/// Is `text` a decimal exponent with an optional sign, which may be empty for decimal literals.
+fn is_float_exponent(text: &str, may_be_empty: bool) -> bool {
+    let digits = text.strip_prefix(['-', '+']).unwrap_or(text);
+
+    if digits.is_empty() {
+        return may_be_empty;
     }
 
-    /// Get name of Struct [`Type`].
-    pub fn get_struct_name(&self) -> String {
-        let name = unsafe { CString::from_raw(LLVMGetStructName(self.get()) as *mut _) };
-        cstring_to_string(name)
+    digits.chars().all(|c| c.is_ascii_digit())
+}
+
+/// A Pointer [`Type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PointerType<'ctx>(Type<'ctx>);
+
+impl<'ctx> PointerType<'ctx> {
+    /// Create a new [`PointerType`] from a Pointer [`Type`].
+    pub(crate) fn new(ty: Type<'ctx>) -> Self {
+        Self(ty)
+    }
+
+    /// Get the [`PointerType`] for a [`Type`], if it is a Pointer [`Type`].
+    pub fn from_type(ty: Type<'ctx>) -> Option<Self> {
+        if ty.get_type_kind() != TypeKind::Pointer {
+            return None;
+        }
+
+        Some(Self(ty))
+    }
+
+    /// Get the underlying [`Type`].
+    pub fn as_type(&self) -> Type<'ctx> {
+        self.0
+    }
+
+    /// Is pointer opaque.
+    pub fn is_opaque(&self) -> bool {
+        unsafe { LLVMPointerTypeIsOpaque(self.get()) == 1 }
+    }
+}
+
+type_wrapper!(PointerType);
+
+/// A literal or named Struct [`Type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StructType<'ctx>(Type<'ctx>);
+
+impl<'ctx> StructType<'ctx> {
+    /// Create a new [`StructType`] from a Struct [`Type`].
+    pub(crate) fn new(ty: Type<'ctx>) -> Self {
+        Self(ty)
+    }
+
+    /// Get the [`StructType`] for a [`Type`], if it is a Struct [`Type`].
+    pub fn from_type(ty: Type<'ctx>) -> Option<Self> {
+        if ty.get_type_kind() != TypeKind::Struct {
+            return None;
+        }
+
+        Some(Self(ty))
+    }
+
+    /// Get the underlying [`Type`].
+    pub fn as_type(&self) -> Type<'ctx> {
+        self.0
+    }
+
+    /// Get name of the struct, literal structs have no name.
+    pub fn get_name(&self) -> Option<String> {
+        unsafe { borrowed_to_string(LLVMGetStructName(self.get())) }
     }
 
     /// Get struct element count.
-    pub fn get_struct_elemen_count(&self) -> usize {
+    pub fn count_elements(&self) -> usize {
         unsafe { LLVMCountStructElementTypes(self.get()) as usize }
     }
 
     /// Get struct element [`Type`]s.
-    pub fn get_struct_element_tys(&self) -> Vec<Type> {
-        let element_count = self.get_struct_elemen_count();
+    pub fn get_element_tys(&self) -> Vec<Type<'ctx>> {
+        let element_count = self.count_elements();
         let mut element_tys = vec![null_mut(); element_count];
 
         unsafe { LLVMGetStructElementTypes(self.get(), element_tys.as_mut_ptr()) };
 
-        element_tys.into_iter().map(|ty| Type::new(ty)).collect()
+        element_tys.into_iter().map(Type::new).collect()
     }
 
     /// Get struct element [`Type`] at index.
-    pub fn get_struct_element_ty(&self, index: usize) -> Option<Type> {
-        let ty = unsafe { LLVMStructGetTypeAtIndex(self.get(), index as u32) };
-
-        if ty == null_mut() {
+    pub fn get_element_ty(&self, index: usize) -> Option<Type<'ctx>> {
+        if index >= self.count_elements() {
             return None;
         }
 
-        Some(Type::new(ty))
+        unsafe {
+            Some(Type::new(LLVMStructGetTypeAtIndex(
+                self.get(),
+                index as u32,
+            )))
+        }
     }
 
     /// Is struct packed.
-    pub fn is_struct_packed(&self) -> bool {
+    pub fn is_packed(&self) -> bool {
         unsafe { LLVMIsPackedStruct(self.get()) == 1 }
     }
 
     /// Is struct opaque.
-    pub fn is_struct_opaque(&self) -> bool {
+    pub fn is_opaque(&self) -> bool {
         unsafe { LLVMIsOpaqueStruct(self.get()) == 1 }
     }
 
     /// Is struct literal.
-    pub fn is_struct_literal(&self) -> bool {
+    pub fn is_literal(&self) -> bool {
         unsafe { LLVMIsLiteralStruct(self.get()) == 1 }
     }
 
-    /// Get Integer [`Type`] bit width.
-    pub fn get_int_type_width(&self) -> u32 {
-        unsafe { LLVMGetIntTypeWidth(self.get()) }
+    /// Create a constant of this named [`StructType`].
+    pub fn const_named_struct(&self, values: &[Value<'ctx>]) -> Value<'ctx> {
+        let mut values = values
+            .iter()
+            .map(|value| value.get())
+            .collect::<Vec<LLVMValueRef>>();
+
+        unsafe {
+            Value::new(LLVMConstNamedStruct(
+                self.get(),
+                values.as_mut_ptr(),
+                values.len() as u32,
+            ))
+        }
+    }
+}
+
+type_wrapper!(StructType);
+
+/// An Array [`Type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArrayType<'ctx>(Type<'ctx>);
+
+impl<'ctx> ArrayType<'ctx> {
+    /// Create a new [`ArrayType`] from an Array [`Type`].
+    pub(crate) fn new(ty: Type<'ctx>) -> Self {
+        Self(ty)
+    }
+
+    /// Get the [`ArrayType`] for a [`Type`], if it is an Array [`Type`].
+    pub fn from_type(ty: Type<'ctx>) -> Option<Self> {
+        if ty.get_type_kind() != TypeKind::Array {
+            return None;
+        }
+
+        Some(Self(ty))
+    }
+
+    /// Get the underlying [`Type`].
+    pub fn as_type(&self) -> Type<'ctx> {
+        self.0
     }
 
     /// Get element [`Type`].
-    pub fn get_element_type(&self) -> Type {
+    pub fn get_element_type(&self) -> Type<'ctx> {
         unsafe { Type::new(LLVMGetElementType(self.get())) }
     }
 
-    /// Get array length [`Type`].
-    pub fn get_array_length(&self) -> usize {
+    /// Get array length.
+    pub fn get_length(&self) -> usize {
         unsafe { LLVMGetArrayLength2(self.get()) as usize }
     }
+}
+
+type_wrapper!(ArrayType);
+
+/// A fixed or scalable Vector [`Type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VectorType<'ctx>(Type<'ctx>);
+
+impl<'ctx> VectorType<'ctx> {
+    /// Create a new [`VectorType`] from a Vector [`Type`].
+    pub(crate) fn new(ty: Type<'ctx>) -> Self {
+        Self(ty)
+    }
 
-    /// Is pointer opaque.
-    pub fn is_pointer_opaque(&self) -> bool {
-        unsafe { LLVMPointerTypeIsOpaque(self.get()) == 1 }
+    /// Get the [`VectorType`] for a [`Type`], if it is a fixed or scalable Vector [`Type`].
+    pub fn from_type(ty: Type<'ctx>) -> Option<Self> {
+        if !matches!(
+            ty.get_type_kind(),
+            TypeKind::Vector | TypeKind::ScalableVector
+        ) {
+            return None;
+        }
+
+        Some(Self(ty))
+    }
+
+    /// Get the underlying [`Type`].
+    pub fn as_type(&self) -> Type<'ctx> {
+        self.0
+    }
+
+    /// Get element [`Type`].
+    pub fn get_element_type(&self) -> Type<'ctx> {
+        unsafe { Type::new(LLVMGetElementType(self.get())) }
+    }
+
+    /// Get the number of elements, the minimum number for scalable vectors.
+    pub fn get_size(&self) -> u32 {
+        unsafe { LLVMGetVectorSize(self.get()) }
+    }
+
+    /// Is this a scalable `<vscale x N x T>` vector.
+    pub fn is_scalable(&self) -> bool {
+        self.0.get_type_kind() == TypeKind::ScalableVector
+    }
+
+    /// Create a constant of this [`VectorType`] with every bit set, if its elements are
+    /// integers.
+    pub fn const_all_ones(&self) -> Option<IntValue<'ctx>> {
+        if self.get_element_type().get_type_kind() != TypeKind::Integer {
+            return None;
+        }
+
+        unsafe { Some(IntValue::new(Value::new(LLVMConstAllOnes(self.get())))) }
+    }
+}
+
+type_wrapper!(VectorType);
+
+/// A Function [`Type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FunctionType<'ctx>(Type<'ctx>);
+
+impl<'ctx> FunctionType<'ctx> {
+    /// Create a new [`FunctionType`] from a Function [`Type`].
+    pub(crate) fn new(ty: Type<'ctx>) -> Self {
+        Self(ty)
+    }
+
+    /// Get the [`FunctionType`] for a [`Type`], if it is a Function [`Type`].
+    pub fn from_type(ty: Type<'ctx>) -> Option<Self> {
+        if ty.get_type_kind() != TypeKind::Function {
+            return None;
+        }
+
+        Some(Self(ty))
+    }
+
+    /// Get the underlying [`Type`].
+    pub fn as_type(&self) -> Type<'ctx> {
+        self.0
+    }
+
+    /// Get the return [`Type`].
+    pub fn get_return_type(&self) -> Type<'ctx> {
+        unsafe { Type::new(LLVMGetReturnType(self.get())) }
+    }
+
+    /// Get the number of parameters.
+    pub fn count_params(&self) -> usize {
+        unsafe { LLVMCountParamTypes(self.get()) as usize }
+    }
+
+    /// Get the parameter [`Type`]s.
+    pub fn get_param_types(&self) -> Vec<Type<'ctx>> {
+        let mut param_tys = vec![null_mut(); self.count_params()];
+
+        unsafe { LLVMGetParamTypes(self.get(), param_tys.as_mut_ptr()) };
+
+        param_tys.into_iter().map(Type::new).collect()
+    }
+
+    /// Does the function take a variable number of arguments.
+    pub fn is_var_arg(&self) -> bool {
+        unsafe { LLVMIsFunctionVarArg(self.get()) == 1 }
+    }
+}
+
+type_wrapper!(FunctionType);
+
+/// The kind of a [`Type`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TypeKind {
+    Void,
+    Half,
+    Float,
+    Double,
+    X86Fp80,
+    Fp128,
+    PpcFp128,
+    Label,
+    Integer,
+    Function,
+    Struct,
+    Array,
+    Pointer,
+    Vector,
+    Metadata,
+    X86Mmx,
+    Token,
+    ScalableVector,
+    BFloat,
+    X86Amx,
+    TargetExt,
+}
+
+impl TypeKind {
+    /// Is this the kind of a Float [`Type`].
+    pub fn is_float(&self) -> bool {
+        matches!(
+            self,
+            Self::Half
+                | Self::BFloat
+                | Self::Float
+                | Self::Double
+                | Self::X86Fp80
+                | Self::Fp128
+                | Self::PpcFp128
+        )
+    }
+}
+
+impl From<TypeKind> for LLVMTypeKind {
+    fn from(value: TypeKind) -> Self {
+        match value {
+            TypeKind::Void => Self::LLVMVoidTypeKind,
+            TypeKind::Half => Self::LLVMHalfTypeKind,
+            TypeKind::Float => Self::LLVMFloatTypeKind,
+            TypeKind::Double => Self::LLVMDoubleTypeKind,
+            TypeKind::X86Fp80 => Self::LLVMX86_FP80TypeKind,
+            TypeKind::Fp128 => Self::LLVMFP128TypeKind,
+            TypeKind::PpcFp128 => Self::LLVMPPC_FP128TypeKind,
+            TypeKind::Label => Self::LLVMLabelTypeKind,
+            TypeKind::Integer => Self::LLVMIntegerTypeKind,
+            TypeKind::Function => Self::LLVMFunctionTypeKind,
+            TypeKind::Struct => Self::LLVMStructTypeKind,
+            TypeKind::Array => Self::LLVMArrayTypeKind,
+            TypeKind::Pointer => Self::LLVMPointerTypeKind,
+            TypeKind::Vector => Self::LLVMVectorTypeKind,
+            TypeKind::Metadata => Self::LLVMMetadataTypeKind,
+            TypeKind::X86Mmx => Self::LLVMX86_MMXTypeKind,
+            TypeKind::Token => Self::LLVMTokenTypeKind,
+            TypeKind::ScalableVector => Self::LLVMScalableVectorTypeKind,
+            TypeKind::BFloat => Self::LLVMBFloatTypeKind,
+            TypeKind::X86Amx => Self::LLVMX86_AMXTypeKind,
+            TypeKind::TargetExt => Self::LLVMTargetExtTypeKind,
+        }
+    }
+}
+
+impl From<LLVMTypeKind> for TypeKind {
+    fn from(value: LLVMTypeKind) -> Self {
+        match value {
+            LLVMTypeKind::LLVMVoidTypeKind => Self::Void,
+            LLVMTypeKind::LLVMHalfTypeKind => Self::Half,
+            LLVMTypeKind::LLVMFloatTypeKind => Self::Float,
+            LLVMTypeKind::LLVMDoubleTypeKind => Self::Double,
+            LLVMTypeKind::LLVMX86_FP80TypeKind => Self::X86Fp80,
+            LLVMTypeKind::LLVMFP128TypeKind => Self::Fp128,
+            LLVMTypeKind::LLVMPPC_FP128TypeKind => Self::PpcFp128,
+            LLVMTypeKind::LLVMLabelTypeKind => Self::Label,
+            LLVMTypeKind::LLVMIntegerTypeKind => Self::Integer,
+            LLVMTypeKind::LLVMFunctionTypeKind => Self::Function,
+            LLVMTypeKind::LLVMStructTypeKind => Self::Struct,
+            LLVMTypeKind::LLVMArrayTypeKind => Self::Array,
+            LLVMTypeKind::LLVMPointerTypeKind => Self::Pointer,
+            LLVMTypeKind::LLVMVectorTypeKind => Self::Vector,
+            LLVMTypeKind::LLVMMetadataTypeKind => Self::Metadata,
+            LLVMTypeKind::LLVMX86_MMXTypeKind => Self::X86Mmx,
+            LLVMTypeKind::LLVMTokenTypeKind => Self::Token,
+            LLVMTypeKind::LLVMScalableVectorTypeKind => Self::ScalableVector,
+            LLVMTypeKind::LLVMBFloatTypeKind => Self::BFloat,
+            LLVMTypeKind::LLVMX86_AMXTypeKind => Self::X86Amx,
+            LLVMTypeKind::LLVMTargetExtTypeKind => Self::TargetExt,
+        }
     }
 }
 
@@ -1,15 +1,52 @@
-use std::{ffi::CString, sync::LazyLock};
+use std::{
+    ffi::{c_char, CStr, CString},
+    fmt,
+    ptr::NonNull,
+};
 
-pub(crate) static EMPTY_TWINE: LazyLock<CString> = LazyLock::new(|| CString::new("").unwrap());
+use llvm_sys::core::LLVMDisposeMessage;
 
 pub(crate) fn string_to_cstring(string: String) -> CString {
     CString::new(string).expect("failed to convert String to CString")
 }
 
-#[allow(unused)]
-pub(crate) fn cstring_to_string(cstring: CString) -> String {
-    cstring
-        .to_str()
-        .expect("failed to convert CString to String")
-        .to_string()
+/// Copy a C string that is still owned by LLVM into a [`String`].
+///
+/// Returns [`None`] if `pointer` is null.
+pub(crate) unsafe fn borrowed_to_string(pointer: *const c_char) -> Option<String> {
+    if pointer.is_null() {
+        return None;
+    }
+
+    Some(CStr::from_ptr(pointer).to_string_lossy().into_owned())
+}
+
+/// A C string allocated by LLVM, freed with [`LLVMDisposeMessage`] on drop.
+#[derive(Debug)]
+pub(crate) struct LlvmString(NonNull<c_char>);
+
+impl LlvmString {
+    /// Take ownership of a message returned by LLVM.
+    ///
+    /// Returns [`None`] if `pointer` is null.
+    pub(crate) unsafe fn new(pointer: *mut c_char) -> Option<Self> {
+        NonNull::new(pointer).map(Self)
+    }
+
+    /// Get the string as a [`CStr`].
+    pub(crate) fn as_c_str(&self) -> &CStr {
+        unsafe { CStr::from_ptr(self.0.as_ptr()) }
+    }
+}
+
+impl fmt::Display for LlvmString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.as_c_str().to_string_lossy())
+    }
+}
+
+impl Drop for LlvmString {
+    fn drop(&mut self) {
+        unsafe { LLVMDisposeMessage(self.0.as_ptr()) }
+    }
 }
@@ -1,22 +1,80 @@
-use std::ptr::null_mut;
+use std::{fmt, iter, marker::PhantomData, ptr::null_mut, slice};
 
 use llvm_sys::{
-    analysis::{LLVMVerifierFailureAction, LLVMVerifyFunction},
-    core::{LLVMDeleteFunction, LLVMGetParam, LLVMSetLinkage, LLVMTypeOf},
+    analysis::{LLVMVerifierFailureAction, LLVMVerifyFunction, LLVMVerifyModule},
+    core::{
+        LLVMAddCase, LLVMAddDestination, LLVMAddIncoming, LLVMConstAdd, LLVMConstAddrSpaceCast,
+        LLVMConstBitCast, LLVMConstExtractElement, LLVMConstFCmp, LLVMConstGEP2, LLVMConstICmp,
+        LLVMConstInBoundsGEP2, LLVMConstInsertElement, LLVMConstIntGetSExtValue,
+        LLVMConstIntGetZExtValue, LLVMConstIntToPtr, LLVMConstMul, LLVMConstNSWAdd,
+        LLVMConstNSWMul, LLVMConstNSWNeg, LLVMConstNSWSub, LLVMConstNUWAdd, LLVMConstNUWMul,
+        LLVMConstNUWNeg, LLVMConstNUWSub, LLVMConstNeg, LLVMConstNot, LLVMConstPointerCast,
+        LLVMConstPtrToInt, LLVMConstShl, LLVMConstShuffleVector, LLVMConstSub, LLVMConstTrunc,
+        LLVMConstTruncOrBitCast, LLVMConstXor, LLVMCountIncoming, LLVMCountParams,
+        LLVMDeleteFunction, LLVMGetElementType, LLVMGetExact, LLVMGetFirstUse, LLVMGetGlobalParent,
+        LLVMGetIncomingBlock, LLVMGetIncomingValue, LLVMGetInstructionOpcode,
+        LLVMGetInstructionParent, LLVMGetIsDisjoint, LLVMGetNSW, LLVMGetNUW, LLVMGetNextUse,
+        LLVMGetParam, LLVMGetUser, LLVMGetValueName2, LLVMGlobalGetValueType,
+        LLVMInstructionEraseFromParent, LLVMIsAConstantInt, LLVMIsAFunction, LLVMIsAIndirectBrInst,
+        LLVMIsAInstruction, LLVMIsAPHINode, LLVMIsASwitchInst, LLVMIsConstant, LLVMIsNull,
+        LLVMPrintValueToString, LLVMReplaceAllUsesWith, LLVMSetExact, LLVMSetIsDisjoint,
+        LLVMSetNSW, LLVMSetNUW, LLVMSetValueName2, LLVMTypeOf,
+    },
     prelude::*,
-    LLVMLinkage,
 };
 
-use crate::ty::Type;
+use crate::{
+    basic_block::BasicBlock,
+    builder::{IntPredicate, Opcode, RealPredicate},
+    context::Context,
+    error::Error,
+    global_variable::GlobalValue,
+    ty::{FunctionType, IntType, PointerType, Type, TypeKind},
+    util::LlvmString,
+};
+
+/// Implement raw access, the conversion into [`Value`] and [`fmt::Display`] for a typed
+/// wrapper around [`Value`].
+macro_rules! value_wrapper {
+    ($wrapper:ident) => {
+        impl $wrapper<'_> {
+            #[inline]
+            #[allow(dead_code)]
+            /// Get inner [`LLVMValueRef`](llvm_sys::prelude::LLVMValueRef).
+            pub(crate) fn get(&self) -> llvm_sys::prelude::LLVMValueRef {
+                self.as_value().get()
+            }
+        }
+
+        impl<'ctx> From<$wrapper<'ctx>> for $crate::value::Value<'ctx> {
+            fn from(value: $wrapper<'ctx>) -> Self {
+                value.as_value()
+            }
+        }
+
+        impl std::fmt::Display for $wrapper<'_> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.as_value().fmt(f)
+            }
+        }
+    };
+}
+
+pub(crate) use value_wrapper;
 
-#[derive(Debug, Clone)]
-pub struct Value(pub(crate) LLVMValueRef);
+/// Are all `values` constants, as LLVM requires of every constant expression operand.
+fn all_constant<'ctx>(values: impl IntoIterator<Item = Value<'ctx>>) -> bool {
+    values.into_iter().all(|value| value.is_constant())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Value<'ctx>(LLVMValueRef, PhantomData<&'ctx Context>);
 
-impl Value {
+impl<'ctx> Value<'ctx> {
     /// Create a new [`Value`] from a [`LLVMValueRef`].
-    pub(crate) fn new(pointer: LLVMValueRef) -> Value {
-        assert_ne!(pointer, null_mut(), "value pointer is null");
-        Self(pointer)
+    pub(crate) fn new(pointer: LLVMValueRef) -> Self {
+        assert!(!pointer.is_null(), "value pointer is null");
+        Self(pointer, PhantomData)
     }
 
     #[inline]
@@ -25,32 +83,1070 @@ impl Value {
         self.0
     }
 
-    /// Get function parameter [`Value`].
-    pub fn get_param(&self, index: usize) -> Value {
-        unsafe { Value::new(LLVMGetParam(self.get(), index as u32)) }
+    /// Get the users of this [`Value`], once for every use.
+    pub fn get_users(&self) -> Vec<Value<'ctx>> {
+        let mut users = Vec::new();
+        let mut current = unsafe { LLVMGetFirstUse(self.get()) };
+
+        while !current.is_null() {
+            unsafe {
+                users.push(Value::new(LLVMGetUser(current)));
+                current = LLVMGetNextUse(current);
+            }
+        }
+
+        users
+    }
+
+    /// Replace all uses of this [`Value`] with another [`Value`].
+    pub fn replace_all_uses_with(&self, value: &Value<'ctx>) {
+        unsafe { LLVMReplaceAllUsesWith(self.get(), value.get()) }
+    }
+
+    /// Set the name of this [`Value`], `void` values cannot be named and are left unchanged.
+    pub fn set_name<S: ToString>(&self, name: S) {
+        if self.get_type().get_type_kind() == TypeKind::Void {
+            return;
+        }
+
+        let name = name.to_string();
+
+        unsafe { LLVMSetValueName2(self.get(), name.as_ptr() as *const _, name.len()) }
     }
 
-    /// Set the linkage of a Function.
-    pub fn set_linkage(&self, linkage: LLVMLinkage) {
-        unsafe { LLVMSetLinkage(self.get(), linkage) }
+    /// Get the name of this [`Value`], unnamed values return an empty string.
+    pub fn get_name(&self) -> String {
+        let mut length = 0;
+        let name = unsafe { LLVMGetValueName2(self.get(), &mut length) };
+
+        if name.is_null() {
+            return String::new();
+        }
+
+        let name = unsafe { slice::from_raw_parts(name as *const u8, length) };
+        String::from_utf8_lossy(name).into_owned()
     }
 
     /// Get [`Type`] of the current [`Value`].
-    pub fn get_type(&self) -> Type {
+    pub fn get_type(&self) -> Type<'ctx> {
         unsafe { Type::new(LLVMTypeOf(self.get())) }
     }
 
-    /// Verify a function, returns `true` if the function is valid.
-    pub fn verify_function(&self, action: VerifierFailureAction) -> bool {
-        unsafe { LLVMVerifyFunction(self.get(), action.into()) != 1 }
+    /// Is this [`Value`] a constant.
+    pub fn is_constant(&self) -> bool {
+        unsafe { LLVMIsConstant(self.get()) == 1 }
+    }
+
+    /// Is this [`Value`] the null constant of its type.
+    pub fn is_null(&self) -> bool {
+        unsafe { LLVMIsNull(self.get()) == 1 }
+    }
+
+    /// Create a constant `BitCast` expression.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_bit_cast(&self, dest_ty: &Type<'ctx>) -> Option<Value<'ctx>> {
+        if !all_constant([*self]) {
+            return None;
+        }
+
+        unsafe { Some(Value::new(LLVMConstBitCast(self.get(), dest_ty.get()))) }
+    }
+
+    /// Create a constant `Trunc` or `BitCast` expression.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_trunc_or_bit_cast(&self, dest_ty: &Type<'ctx>) -> Option<Value<'ctx>> {
+        if !all_constant([*self]) {
+            return None;
+        }
+
+        unsafe {
+            Some(Value::new(LLVMConstTruncOrBitCast(
+                self.get(),
+                dest_ty.get(),
+            )))
+        }
+    }
+
+    /// Create a constant `ExtractElement` expression.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_extract_element(&self, index: &IntValue<'ctx>) -> Option<Value<'ctx>> {
+        if !all_constant([*self, index.0]) {
+            return None;
+        }
+
+        unsafe { Some(Value::new(LLVMConstExtractElement(self.get(), index.get()))) }
+    }
+
+    /// Create a constant `InsertElement` expression.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_insert_element(
+        &self,
+        element: &Value<'ctx>,
+        index: &IntValue<'ctx>,
+    ) -> Option<Value<'ctx>> {
+        if !all_constant([*self, *element, index.0]) {
+            return None;
+        }
+
+        unsafe {
+            Some(Value::new(LLVMConstInsertElement(
+                self.get(),
+                element.get(),
+                index.get(),
+            )))
+        }
+    }
+
+    /// Create a constant `ShuffleVector` expression.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_shuffle_vector(
+        &self,
+        right: &Value<'ctx>,
+        mask: &Value<'ctx>,
+    ) -> Option<Value<'ctx>> {
+        if !all_constant([*self, *right, *mask]) {
+            return None;
+        }
+
+        unsafe {
+            Some(Value::new(LLVMConstShuffleVector(
+                self.get(),
+                right.get(),
+                mask.get(),
+            )))
+        }
+    }
+
+    /// Get the [`TypeKind`] of this [`Value`], or of its elements if it is a Vector.
+    fn get_scalar_type_kind(&self) -> TypeKind {
+        let ty = self.get_type();
+
+        match ty.get_type_kind() {
+            TypeKind::Vector | TypeKind::ScalableVector => unsafe {
+                Type::new(LLVMGetElementType(ty.get())).get_type_kind()
+            },
+            kind => kind,
+        }
+    }
+}
+
+impl fmt::Display for Value<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ir = unsafe { LlvmString::new(LLVMPrintValueToString(self.get())) };
+        ir.map_or(Ok(()), |ir| ir.fmt(f))
+    }
+}
+
+/// A function of a [`Module`](crate::module::Module).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FunctionValue<'ctx>(Value<'ctx>);
+
+impl<'ctx> FunctionValue<'ctx> {
+    /// Create a new [`FunctionValue`] from a function [`Value`].
+    pub(crate) fn new(value: Value<'ctx>) -> Self {
+        Self(value)
+    }
+
+    /// Get the [`FunctionValue`] for a [`Value`], if it is a function.
+    pub fn from_value(value: Value<'ctx>) -> Option<Self> {
+        if unsafe { LLVMIsAFunction(value.get()) }.is_null() {
+            return None;
+        }
+
+        Some(Self(value))
+    }
+
+    /// Get the underlying [`Value`].
+    pub fn as_value(&self) -> Value<'ctx> {
+        self.0
+    }
+
+    /// Get this function as a [`GlobalValue`].
+    pub fn as_global_value(&self) -> GlobalValue<'ctx> {
+        GlobalValue::new(self.0)
+    }
+
+    /// Get this function as a [`PointerValue`], e.g. to store it or call it indirectly.
+    pub fn as_pointer_value(&self) -> PointerValue<'ctx> {
+        PointerValue::new(self.0)
+    }
+
+    /// Get the [`FunctionType`] this function was declared with.
+    pub fn get_function_type(&self) -> FunctionType<'ctx> {
+        unsafe { FunctionType::new(Type::new(LLVMGlobalGetValueType(self.0.get()))) }
+    }
+
+    /// Get the number of parameters.
+    pub fn count_params(&self) -> usize {
+        unsafe { LLVMCountParams(self.0.get()) as usize }
+    }
+
+    /// Get function parameter [`Value`] at index.
+    pub fn get_param(&self, index: usize) -> Option<Value<'ctx>> {
+        if index >= self.count_params() {
+            return None;
+        }
+
+        unsafe { Some(Value::new(LLVMGetParam(self.0.get(), index as u32))) }
+    }
+
+    /// Get all function parameter [`Value`]s.
+    pub fn get_params(&self) -> Vec<Value<'ctx>> {
+        (0..self.count_params())
+            .filter_map(|index| self.get_param(index))
+            .collect()
+    }
+
+    /// Verify this function.
+    ///
+    /// LLVM only collects diagnostics per module, so on failure the error carries the
+    /// diagnostics of the whole parent module.
+    pub fn verify(&self, action: VerifierFailureAction) -> Result<(), Error> {
+        if unsafe { LLVMVerifyFunction(self.0.get(), action.into()) } == 0 {
+            return Ok(());
+        }
+
+        let module = unsafe { LLVMGetGlobalParent(self.0.get()) };
+        let mut message = null_mut();
+
+        if !module.is_null() {
+            unsafe {
+                LLVMVerifyModule(
+                    module,
+                    LLVMVerifierFailureAction::LLVMReturnStatusAction,
+                    &mut message,
+                )
+            };
+        }
+
+        Err(Error::Verification(
+            unsafe { LlvmString::new(message) }
+                .map(|message| message.to_string())
+                .unwrap_or_else(|| String::from("function failed verification")),
+        ))
+    }
+
+    /// Remove this function from its module and delete it.
+    ///
+    /// # Safety
+    ///
+    /// The function must have no remaining uses, and since handles are [`Copy`], no other copy of
+    /// this one may be used afterwards.
+    pub unsafe fn delete(self) {
+        unsafe { LLVMDeleteFunction(self.0.get()) };
+    }
+}
+
+value_wrapper!(FunctionValue);
+
+/// An instruction inside a [`BasicBlock`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InstructionValue<'ctx>(Value<'ctx>);
+
+impl<'ctx> InstructionValue<'ctx> {
+    /// Create a new [`InstructionValue`] from an instruction [`Value`].
+    pub(crate) fn new(value: Value<'ctx>) -> Self {
+        Self(value)
+    }
+
+    /// Get the [`InstructionValue`] for a [`Value`], if it is an instruction.
+    pub fn from_value(value: Value<'ctx>) -> Option<Self> {
+        if unsafe { LLVMIsAInstruction(value.get()) }.is_null() {
+            return None;
+        }
+
+        Some(Self(value))
+    }
+
+    /// Get the underlying [`Value`].
+    pub fn as_value(&self) -> Value<'ctx> {
+        self.0
+    }
+
+    /// Remove this instruction from its [`BasicBlock`] and delete it.
+    ///
+    /// # Safety
+    ///
+    /// The instruction must have no remaining uses, and since handles are [`Copy`], no other
+    /// copy of this one may be used afterwards.
+    pub unsafe fn erase_from_parent(self) {
+        unsafe { LLVMInstructionEraseFromParent(self.0.get()) }
+    }
+
+    /// Get the [`BasicBlock`] containing this instruction.
+    pub fn get_parent_block(&self) -> Option<BasicBlock<'ctx>> {
+        let block = unsafe { LLVMGetInstructionParent(self.0.get()) };
+
+        if block.is_null() {
+            return None;
+        }
+
+        Some(BasicBlock::new(block))
+    }
+
+    /// Get the [`Opcode`] of this instruction.
+    pub fn get_opcode(&self) -> Opcode {
+        unsafe { LLVMGetInstructionOpcode(self.0.get()) }.into()
+    }
+
+    /// Can this instruction carry the `nsw` and `nuw` flags.
+    fn has_wrap_flags(&self) -> bool {
+        matches!(
+            self.get_opcode(),
+            Opcode::Add | Opcode::Sub | Opcode::Mul | Opcode::Shl
+        )
+    }
+
+    /// Can this instruction carry the `exact` flag.
+    fn has_exact_flag(&self) -> bool {
+        matches!(
+            self.get_opcode(),
+            Opcode::UDiv | Opcode::SDiv | Opcode::LShr | Opcode::AShr
+        )
+    }
+
+    /// Set the `nsw` flag of an `Add`, `Sub`, `Mul` or `Shl` instruction.
+    ///
+    /// Returns `false`, leaving the instruction unchanged, for any other opcode.
+    pub fn set_nsw(&self, nsw: bool) -> bool {
+        if !self.has_wrap_flags() {
+            return false;
+        }
+
+        unsafe { LLVMSetNSW(self.0.get(), nsw as i32) };
+        true
+    }
+
+    /// Does the `Add`, `Sub`, `Mul` or `Shl` instruction have the `nsw` flag, `None` for
+    /// any other opcode.
+    pub fn has_nsw(&self) -> Option<bool> {
+        if !self.has_wrap_flags() {
+            return None;
+        }
+
+        unsafe { Some(LLVMGetNSW(self.0.get()) == 1) }
+    }
+
+    /// Set the `nuw` flag of an `Add`, `Sub`, `Mul` or `Shl` instruction.
+    ///
+    /// Returns `false`, leaving the instruction unchanged, for any other opcode.
+    pub fn set_nuw(&self, nuw: bool) -> bool {
+        if !self.has_wrap_flags() {
+            return false;
+        }
+
+        unsafe { LLVMSetNUW(self.0.get(), nuw as i32) };
+        true
+    }
+
+    /// Does the `Add`, `Sub`, `Mul` or `Shl` instruction have the `nuw` flag, `None` for
+    /// any other opcode.
+    pub fn has_nuw(&self) -> Option<bool> {
+        if !self.has_wrap_flags() {
+            return None;
+        }
+
+        unsafe { Some(LLVMGetNUW(self.0.get()) == 1) }
+    }
+
+    /// Set the `exact` flag of a `UDiv`, `SDiv`, `LShr` or `AShr` instruction.
+    ///
+    /// Returns `false`, leaving the instruction unchanged, for any other opcode.
+    pub fn set_exact(&self, exact: bool) -> bool {
+        if !self.has_exact_flag() {
+            return false;
+        }
+
+        unsafe { LLVMSetExact(self.0.get(), exact as i32) };
+        true
+    }
+
+    /// Is the `UDiv`, `SDiv`, `LShr` or `AShr` instruction `exact`, `None` for any other
+    /// opcode.
+    pub fn is_exact(&self) -> Option<bool> {
+        if !self.has_exact_flag() {
+            return None;
+        }
+
+        unsafe { Some(LLVMGetExact(self.0.get()) == 1) }
+    }
+
+    /// Set the `disjoint` flag of an `Or` instruction.
+    ///
+    /// Returns `false`, leaving the instruction unchanged, for any other opcode.
+    pub fn set_disjoint(&self, disjoint: bool) -> bool {
+        if self.get_opcode() != Opcode::Or {
+            return false;
+        }
+
+        unsafe { LLVMSetIsDisjoint(self.0.get(), disjoint as i32) };
+        true
+    }
+
+    /// Is the `Or` instruction `disjoint`, `None` for any other opcode.
+    pub fn is_disjoint(&self) -> Option<bool> {
+        if self.get_opcode() != Opcode::Or {
+            return None;
+        }
+
+        unsafe { Some(LLVMGetIsDisjoint(self.0.get()) == 1) }
+    }
+}
+
+value_wrapper!(InstructionValue);
+
+/// A `Switch` terminator instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SwitchValue<'ctx>(Value<'ctx>);
+
+impl<'ctx> SwitchValue<'ctx> {
+    /// Create a new [`SwitchValue`] from a `Switch` instruction [`Value`].
+    pub(crate) fn new(value: Value<'ctx>) -> Self {
+        Self(value)
+    }
+
+    /// Get the [`SwitchValue`] for a [`Value`], if it is a `Switch` instruction.
+    pub fn from_value(value: Value<'ctx>) -> Option<Self> {
+        if unsafe { LLVMIsASwitchInst(value.get()) }.is_null() {
+            return None;
+        }
+
+        Some(Self(value))
+    }
+
+    /// Get the underlying [`Value`].
+    pub fn as_value(&self) -> Value<'ctx> {
+        self.0
+    }
+
+    /// Get this switch as an [`InstructionValue`].
+    pub fn as_instruction(&self) -> InstructionValue<'ctx> {
+        InstructionValue::new(self.0)
+    }
+
+    /// Add a case jumping to `block` when the condition equals `on_value`.
+    pub fn add_case(&self, on_value: &IntValue<'ctx>, block: &BasicBlock<'ctx>) {
+        unsafe { LLVMAddCase(self.0.get(), on_value.get(), block.get()) }
+    }
+}
+
+value_wrapper!(SwitchValue);
+
+/// An `IndirectBr` terminator instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IndirectBrValue<'ctx>(Value<'ctx>);
+
+impl<'ctx> IndirectBrValue<'ctx> {
+    /// Create a new [`IndirectBrValue`] from an `IndirectBr` instruction [`Value`].
+    pub(crate) fn new(value: Value<'ctx>) -> Self {
+        Self(value)
+    }
+
+    /// Get the [`IndirectBrValue`] for a [`Value`], if it is an `IndirectBr` instruction.
+    pub fn from_value(value: Value<'ctx>) -> Option<Self> {
+        if unsafe { LLVMIsAIndirectBrInst(value.get()) }.is_null() {
+            return None;
+        }
+
+        Some(Self(value))
+    }
+
+    /// Get the underlying [`Value`].
+    pub fn as_value(&self) -> Value<'ctx> {
+        self.0
+    }
+
+    /// Get this indirect branch as an [`InstructionValue`].
+    pub fn as_instruction(&self) -> InstructionValue<'ctx> {
+        InstructionValue::new(self.0)
+    }
+
+    /// Add a possible destination, whose address may be the branch target.
+    pub fn add_destination(&self, block: &BasicBlock<'ctx>) {
+        unsafe { LLVMAddDestination(self.0.get(), block.get()) }
+    }
+}
+
+value_wrapper!(IndirectBrValue);
+
+/// A [`Value`] of Integer [`Type`], or a Vector of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntValue<'ctx>(Value<'ctx>);
+
+impl<'ctx> IntValue<'ctx> {
+    /// Create a new [`IntValue`] from an Integer [`Value`].
+    pub(crate) fn new(value: Value<'ctx>) -> Self {
+        Self(value)
+    }
+
+    /// Get the [`IntValue`] for a [`Value`], if it is of Integer [`Type`].
+    pub fn from_value(value: Value<'ctx>) -> Option<Self> {
+        if value.get_scalar_type_kind() != TypeKind::Integer {
+            return None;
+        }
+
+        Some(Self(value))
+    }
+
+    /// Get the underlying [`Value`].
+    pub fn as_value(&self) -> Value<'ctx> {
+        self.0
+    }
+
+    /// Get the zero extended value of an Integer constant that fits in 64 bits.
+    pub fn get_zext_value(&self) -> Option<u64> {
+        if !self.is_const_int() {
+            return None;
+        }
+
+        unsafe { Some(LLVMConstIntGetZExtValue(self.0.get())) }
+    }
+
+    /// Get the sign extended value of an Integer constant that fits in 64 bits.
+    pub fn get_sext_value(&self) -> Option<i64> {
+        if !self.is_const_int() {
+            return None;
+        }
+
+        unsafe { Some(LLVMConstIntGetSExtValue(self.0.get())) }
+    }
+
+    fn is_const_int(&self) -> bool {
+        let is_const_int = !unsafe { LLVMIsAConstantInt(self.0.get()) }.is_null();
+
+        is_const_int && IntType::new(self.0.get_type()).get_width() <= 64
+    }
+
+    /// Create a constant `Neg` expression.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_neg(&self) -> Option<IntValue<'ctx>> {
+        if !all_constant([self.0]) {
+            return None;
+        }
+
+        unsafe { Some(IntValue::new(Value::new(LLVMConstNeg(self.0.get())))) }
     }
 
-    /// Delete this function.
-    pub fn delete_function(&self) {
-        unsafe { LLVMDeleteFunction(self.get()) };
+    /// Create a constant `Neg` expression with the `nsw` flag.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_nsw_neg(&self) -> Option<IntValue<'ctx>> {
+        if !all_constant([self.0]) {
+            return None;
+        }
+
+        unsafe { Some(IntValue::new(Value::new(LLVMConstNSWNeg(self.0.get())))) }
+    }
+
+    /// Create a constant `Neg` expression with the `nuw` flag.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_nuw_neg(&self) -> Option<IntValue<'ctx>> {
+        if !all_constant([self.0]) {
+            return None;
+        }
+
+        unsafe { Some(IntValue::new(Value::new(LLVMConstNUWNeg(self.0.get())))) }
+    }
+
+    /// Create a constant `Not` expression.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_not(&self) -> Option<IntValue<'ctx>> {
+        if !all_constant([self.0]) {
+            return None;
+        }
+
+        unsafe { Some(IntValue::new(Value::new(LLVMConstNot(self.0.get())))) }
+    }
+
+    /// Create a constant `Add` expression.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_add(&self, right: &IntValue<'ctx>) -> Option<IntValue<'ctx>> {
+        if !all_constant([self.0, right.0]) {
+            return None;
+        }
+
+        unsafe {
+            Some(IntValue::new(Value::new(LLVMConstAdd(
+                self.0.get(),
+                right.0.get(),
+            ))))
+        }
+    }
+
+    /// Create a constant `Add` expression with the `nsw` flag.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_nsw_add(&self, right: &IntValue<'ctx>) -> Option<IntValue<'ctx>> {
+        if !all_constant([self.0, right.0]) {
+            return None;
+        }
+
+        unsafe {
+            Some(IntValue::new(Value::new(LLVMConstNSWAdd(
+                self.0.get(),
+                right.0.get(),
+            ))))
+        }
+    }
+
+    /// Create a constant `Add` expression with the `nuw` flag.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_nuw_add(&self, right: &IntValue<'ctx>) -> Option<IntValue<'ctx>> {
+        if !all_constant([self.0, right.0]) {
+            return None;
+        }
+
+        unsafe {
+            Some(IntValue::new(Value::new(LLVMConstNUWAdd(
+                self.0.get(),
+                right.0.get(),
+            ))))
+        }
+    }
+
+    /// Create a constant `Sub` expression.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_sub(&self, right: &IntValue<'ctx>) -> Option<IntValue<'ctx>> {
+        if !all_constant([self.0, right.0]) {
+            return None;
+        }
+
+        unsafe {
+            Some(IntValue::new(Value::new(LLVMConstSub(
+                self.0.get(),
+                right.0.get(),
+            ))))
+        }
+    }
+
+    /// Create a constant `Sub` expression with the `nsw` flag.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_nsw_sub(&self, right: &IntValue<'ctx>) -> Option<IntValue<'ctx>> {
+        if !all_constant([self.0, right.0]) {
+            return None;
+        }
+
+        unsafe {
+            Some(IntValue::new(Value::new(LLVMConstNSWSub(
+                self.0.get(),
+                right.0.get(),
+            ))))
+        }
+    }
+
+    /// Create a constant `Sub` expression with the `nuw` flag.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_nuw_sub(&self, right: &IntValue<'ctx>) -> Option<IntValue<'ctx>> {
+        if !all_constant([self.0, right.0]) {
+            return None;
+        }
+
+        unsafe {
+            Some(IntValue::new(Value::new(LLVMConstNUWSub(
+                self.0.get(),
+                right.0.get(),
+            ))))
+        }
+    }
+
+    /// Create a constant `Mul` expression.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_mul(&self, right: &IntValue<'ctx>) -> Option<IntValue<'ctx>> {
+        if !all_constant([self.0, right.0]) {
+            return None;
+        }
+
+        unsafe {
+            Some(IntValue::new(Value::new(LLVMConstMul(
+                self.0.get(),
+                right.0.get(),
+            ))))
+        }
+    }
+
+    /// Create a constant `Mul` expression with the `nsw` flag.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_nsw_mul(&self, right: &IntValue<'ctx>) -> Option<IntValue<'ctx>> {
+        if !all_constant([self.0, right.0]) {
+            return None;
+        }
+
+        unsafe {
+            Some(IntValue::new(Value::new(LLVMConstNSWMul(
+                self.0.get(),
+                right.0.get(),
+            ))))
+        }
+    }
+
+    /// Create a constant `Mul` expression with the `nuw` flag.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_nuw_mul(&self, right: &IntValue<'ctx>) -> Option<IntValue<'ctx>> {
+        if !all_constant([self.0, right.0]) {
+            return None;
+        }
+
+        unsafe {
+            Some(IntValue::new(Value::new(LLVMConstNUWMul(
+                self.0.get(),
+                right.0.get(),
+            ))))
+        }
+    }
+
+    /// Create a constant `Xor` expression.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_xor(&self, right: &IntValue<'ctx>) -> Option<IntValue<'ctx>> {
+        if !all_constant([self.0, right.0]) {
+            return None;
+        }
+
+        unsafe {
+            Some(IntValue::new(Value::new(LLVMConstXor(
+                self.0.get(),
+                right.0.get(),
+            ))))
+        }
+    }
+
+    /// Create a constant `Shl` expression.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_shl(&self, right: &IntValue<'ctx>) -> Option<IntValue<'ctx>> {
+        if !all_constant([self.0, right.0]) {
+            return None;
+        }
+
+        unsafe {
+            Some(IntValue::new(Value::new(LLVMConstShl(
+                self.0.get(),
+                right.0.get(),
+            ))))
+        }
+    }
+
+    /// Create a constant `ICmp` expression.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_icmp(&self, op: IntPredicate, right: &IntValue<'ctx>) -> Option<IntValue<'ctx>> {
+        if !all_constant([self.0, right.0]) {
+            return None;
+        }
+
+        unsafe {
+            Some(IntValue::new(Value::new(LLVMConstICmp(
+                op.into(),
+                self.0.get(),
+                right.0.get(),
+            ))))
+        }
+    }
+
+    /// Create a constant `Trunc` expression.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_trunc(&self, dest_ty: &IntType<'ctx>) -> Option<IntValue<'ctx>> {
+        if !all_constant([self.0]) {
+            return None;
+        }
+
+        unsafe {
+            Some(IntValue::new(Value::new(LLVMConstTrunc(
+                self.0.get(),
+                dest_ty.get(),
+            ))))
+        }
+    }
+
+    /// Create a constant `IntToPtr` expression.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_inttoptr(&self, dest_ty: &PointerType<'ctx>) -> Option<PointerValue<'ctx>> {
+        if !all_constant([self.0]) {
+            return None;
+        }
+
+        unsafe {
+            Some(PointerValue::new(Value::new(LLVMConstIntToPtr(
+                self.0.get(),
+                dest_ty.get(),
+            ))))
+        }
+    }
+}
+
+value_wrapper!(IntValue);
+
+/// A [`Value`] of Float [`Type`], or a Vector of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FloatValue<'ctx>(Value<'ctx>);
+
+impl<'ctx> FloatValue<'ctx> {
+    /// Create a new [`FloatValue`] from a Float [`Value`].
+    pub(crate) fn new(value: Value<'ctx>) -> Self {
+        Self(value)
+    }
+
+    /// Get the [`FloatValue`] for a [`Value`], if it is of Float [`Type`].
+    pub fn from_value(value: Value<'ctx>) -> Option<Self> {
+        if !value.get_scalar_type_kind().is_float() {
+            return None;
+        }
+
+        Some(Self(value))
+    }
+
+    /// Get the underlying [`Value`].
+    pub fn as_value(&self) -> Value<'ctx> {
+        self.0
+    }
+
+    /// Create a constant `FCmp` expression.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_fcmp(
+        &self,
+        op: RealPredicate,
+        right: &FloatValue<'ctx>,
+    ) -> Option<IntValue<'ctx>> {
+        if !all_constant([self.0, right.0]) {
+            return None;
+        }
+
+        unsafe {
+            Some(IntValue::new(Value::new(LLVMConstFCmp(
+                op.into(),
+                self.0.get(),
+                right.0.get(),
+            ))))
+        }
+    }
+}
+
+value_wrapper!(FloatValue);
+
+/// A [`Value`] of Pointer [`Type`], or a Vector of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PointerValue<'ctx>(Value<'ctx>);
+
+impl<'ctx> PointerValue<'ctx> {
+    /// Create a new [`PointerValue`] from a Pointer [`Value`].
+    pub(crate) fn new(value: Value<'ctx>) -> Self {
+        Self(value)
+    }
+
+    /// Get the [`PointerValue`] for a [`Value`], if it is of Pointer [`Type`].
+    pub fn from_value(value: Value<'ctx>) -> Option<Self> {
+        if value.get_scalar_type_kind() != TypeKind::Pointer {
+            return None;
+        }
+
+        Some(Self(value))
+    }
+
+    /// Get the underlying [`Value`].
+    pub fn as_value(&self) -> Value<'ctx> {
+        self.0
+    }
+
+    /// Create a constant `GetElementPtr` expression indexing into `ty` from this pointer.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_gep(
+        &self,
+        ty: &Type<'ctx>,
+        indices: &[IntValue<'ctx>],
+    ) -> Option<PointerValue<'ctx>> {
+        if !all_constant(iter::once(self.0).chain(indices.iter().map(|index| index.0))) {
+            return None;
+        }
+
+        let mut indices = indices
+            .iter()
+            .map(|index| index.0.get())
+            .collect::<Vec<LLVMValueRef>>();
+
+        unsafe {
+            Some(PointerValue::new(Value::new(LLVMConstGEP2(
+                ty.get(),
+                self.0.get(),
+                indices.as_mut_ptr(),
+                indices.len() as u32,
+            ))))
+        }
+    }
+
+    /// Create a constant `GetElementPtr inbounds` expression indexing into `ty` from this
+    /// pointer.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_inbounds_gep(
+        &self,
+        ty: &Type<'ctx>,
+        indices: &[IntValue<'ctx>],
+    ) -> Option<PointerValue<'ctx>> {
+        if !all_constant(iter::once(self.0).chain(indices.iter().map(|index| index.0))) {
+            return None;
+        }
+
+        let mut indices = indices
+            .iter()
+            .map(|index| index.0.get())
+            .collect::<Vec<LLVMValueRef>>();
+
+        unsafe {
+            Some(PointerValue::new(Value::new(LLVMConstInBoundsGEP2(
+                ty.get(),
+                self.0.get(),
+                indices.as_mut_ptr(),
+                indices.len() as u32,
+            ))))
+        }
+    }
+
+    /// Create a constant `PtrToInt` expression.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_ptrtoint(&self, dest_ty: &IntType<'ctx>) -> Option<IntValue<'ctx>> {
+        if !all_constant([self.0]) {
+            return None;
+        }
+
+        unsafe {
+            Some(IntValue::new(Value::new(LLVMConstPtrToInt(
+                self.0.get(),
+                dest_ty.get(),
+            ))))
+        }
+    }
+
+    /// Create a constant `AddrSpaceCast` expression.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_addrspacecast(&self, dest_ty: &PointerType<'ctx>) -> Option<PointerValue<'ctx>> {
+        if !all_constant([self.0]) {
+            return None;
+        }
+
+        unsafe {
+            Some(PointerValue::new(Value::new(LLVMConstAddrSpaceCast(
+                self.0.get(),
+                dest_ty.get(),
+            ))))
+        }
+    }
+
+    /// Create a constant pointer cast expression.
+    ///
+    /// Returns `None` if any operand is not a constant.
+    pub fn const_pointer_cast(&self, dest_ty: &PointerType<'ctx>) -> Option<PointerValue<'ctx>> {
+        if !all_constant([self.0]) {
+            return None;
+        }
+
+        unsafe {
+            Some(PointerValue::new(Value::new(LLVMConstPointerCast(
+                self.0.get(),
+                dest_ty.get(),
+            ))))
+        }
+    }
+}
+
+value_wrapper!(PointerValue);
+
+/// A `Phi` instruction, selecting a [`Value`] by the predecessor control came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PhiValue<'ctx>(Value<'ctx>);
+
+impl<'ctx> PhiValue<'ctx> {
+    /// Create a new [`PhiValue`] from a `Phi` instruction [`Value`].
+    pub(crate) fn new(value: Value<'ctx>) -> Self {
+        Self(value)
+    }
+
+    /// Get the [`PhiValue`] for a [`Value`], if it is a `Phi` instruction.
+    pub fn from_value(value: Value<'ctx>) -> Option<Self> {
+        if unsafe { LLVMIsAPHINode(value.get()) }.is_null() {
+            return None;
+        }
+
+        Some(Self(value))
+    }
+
+    /// Get the underlying [`Value`].
+    pub fn as_value(&self) -> Value<'ctx> {
+        self.0
+    }
+
+    /// Get this `Phi` as an [`InstructionValue`].
+    pub fn as_instruction(&self) -> InstructionValue<'ctx> {
+        InstructionValue::new(self.0)
+    }
+
+    /// Add incoming [`Value`]s and the [`BasicBlock`]s they flow from.
+    pub fn add_incoming(&self, incoming: &[(Value<'ctx>, BasicBlock<'ctx>)]) {
+        let (mut values, mut blocks): (Vec<LLVMValueRef>, Vec<LLVMBasicBlockRef>) = incoming
+            .iter()
+            .map(|(value, block)| (value.get(), block.get()))
+            .unzip();
+
+        unsafe {
+            LLVMAddIncoming(
+                self.0.get(),
+                values.as_mut_ptr(),
+                blocks.as_mut_ptr(),
+                values.len() as u32,
+            )
+        }
+    }
+
+    /// Get the number of incoming edges.
+    pub fn count_incoming(&self) -> usize {
+        unsafe { LLVMCountIncoming(self.0.get()) as usize }
+    }
+
+    /// Get the incoming [`Value`] and [`BasicBlock`] at index.
+    pub fn get_incoming(&self, index: usize) -> Option<(Value<'ctx>, BasicBlock<'ctx>)> {
+        if index >= self.count_incoming() {
+            return None;
+        }
+
+        unsafe {
+            Some((
+                Value::new(LLVMGetIncomingValue(self.0.get(), index as u32)),
+                BasicBlock::new(LLVMGetIncomingBlock(self.0.get(), index as u32)),
+            ))
+        }
+    }
+
+    /// Get all incoming [`Value`]s and [`BasicBlock`]s.
+    pub fn get_incomings(&self) -> Vec<(Value<'ctx>, BasicBlock<'ctx>)> {
+        (0..self.count_incoming())
+            .filter_map(|index| self.get_incoming(index))
+            .collect()
     }
 }
 
+value_wrapper!(PhiValue);
+
 /// A list of actions if a verification fails.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum VerifierFailureAction {